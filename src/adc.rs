@@ -0,0 +1,242 @@
+use crate::constants::T0;
+use crate::noise::noise_density_dbm_per_hz;
+use crate::power::{linear_to_db, watts_to_dbm};
+
+/// dB per bit of an ideal quantizer, 20·log₁₀(2) ≈ 6.02.
+const DB_PER_BIT: f64 = 6.020_599_913_279_624;
+/// Full-scale sine to quantization noise offset, 10·log₁₀(1.5) ≈ 1.76.
+const SINE_OFFSET_DB: f64 = 1.760_912_590_556_812_4;
+
+/// Ideal quantization-limited SNR (dB) of an N-bit converter for a
+/// full-scale sine wave.
+///
+/// SNR = 6.02·N + 1.76
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::adc::ideal_snr_db;
+/// assert!((ideal_snr_db(12) - 74.01).abs() < 0.01);
+/// ```
+#[must_use]
+pub fn ideal_snr_db(bits: u32) -> f64 {
+    DB_PER_BIT * bits as f64 + SINE_OFFSET_DB
+}
+
+/// Effective number of bits from a measured SINAD (dB).
+///
+/// ENOB = (SINAD − 1.76) / 6.02
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::adc::enob;
+/// // A 14-bit ADC measuring 70 dB SINAD
+/// assert!((enob(70.0) - 11.33).abs() < 0.01);
+/// ```
+#[doc(alias = "effective number of bits")]
+#[must_use]
+pub fn enob(sinad_db: f64) -> f64 {
+    (sinad_db - SINE_OFFSET_DB) / DB_PER_BIT
+}
+
+/// SINAD (dB) corresponding to an effective number of bits.
+///
+/// SINAD = 6.02·ENOB + 1.76
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::adc::sinad_from_enob;
+/// assert!((sinad_from_enob(10.0) - 61.97).abs() < 0.01);
+/// ```
+#[must_use]
+pub fn sinad_from_enob(enob: f64) -> f64 {
+    DB_PER_BIT * enob + SINE_OFFSET_DB
+}
+
+/// Full-scale sine power (dBm) from the peak-to-peak input range (V) and
+/// input impedance (Ω).
+///
+/// P = (Vpp / 2√2)² / R
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::adc::full_scale_dbm;
+/// // 1.7 Vpp into 100 Ω differential → ~5.6 dBm
+/// assert!((full_scale_dbm(1.7, 100.0) - 5.58).abs() < 0.01);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `vpp` or `impedance` is not positive.
+#[must_use]
+pub fn full_scale_dbm(vpp: f64, impedance: f64) -> f64 {
+    assert!(vpp > 0.0, "full-scale voltage must be positive");
+    assert!(impedance > 0.0, "impedance must be positive");
+    let vrms = vpp / (2.0 * std::f64::consts::SQRT_2);
+    watts_to_dbm(vrms * vrms / impedance)
+}
+
+/// Convert a level relative to full scale (dBFS) to dBm.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::adc::dbfs_to_dbm;
+/// assert!((dbfs_to_dbm(-1.0, 4.0) - 3.0).abs() < 1e-12);
+/// ```
+#[must_use]
+pub fn dbfs_to_dbm(dbfs: f64, full_scale_dbm: f64) -> f64 {
+    dbfs + full_scale_dbm
+}
+
+/// Convert a level in dBm to dB relative to full scale.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::adc::dbm_to_dbfs;
+/// assert!((dbm_to_dbfs(-20.0, 4.0) - (-24.0)).abs() < 1e-12);
+/// ```
+#[must_use]
+pub fn dbm_to_dbfs(dbm: f64, full_scale_dbm: f64) -> f64 {
+    dbm - full_scale_dbm
+}
+
+/// Noise spectral density (dBFS/Hz) of a converter whose SNR (dB) is
+/// measured across the first Nyquist zone.
+///
+/// NSD = −SNR − 10·log₁₀(fₛ / 2)
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::adc::noise_spectral_density_dbfs_per_hz;
+/// // 70 dB SNR at 1 GS/s
+/// let nsd = noise_spectral_density_dbfs_per_hz(70.0, 1e9);
+/// assert!((nsd - (-156.99)).abs() < 0.01);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `sample_rate` is not positive.
+#[doc(alias = "NSD")]
+#[must_use]
+pub fn noise_spectral_density_dbfs_per_hz(snr_db: f64, sample_rate: f64) -> f64 {
+    assert!(sample_rate > 0.0, "sample rate must be positive");
+    -snr_db - linear_to_db(sample_rate / 2.0)
+}
+
+/// Noise spectral density in dBm/Hz referred to the converter input.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::adc::noise_spectral_density_dbm_per_hz;
+/// let nsd = noise_spectral_density_dbm_per_hz(70.0, 1e9, 4.0);
+/// assert!((nsd - (-152.99)).abs() < 0.01);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `sample_rate` is not positive.
+#[must_use]
+pub fn noise_spectral_density_dbm_per_hz(
+    snr_db: f64,
+    sample_rate: f64,
+    full_scale_dbm: f64,
+) -> f64 {
+    noise_spectral_density_dbfs_per_hz(snr_db, sample_rate) + full_scale_dbm
+}
+
+/// Processing gain (dB) from filtering/decimating the Nyquist band down to
+/// a signal bandwidth.
+///
+/// PG = 10·log₁₀(fₛ / 2B)
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::adc::processing_gain_db;
+/// // 1 GS/s decimated to a 10 MHz channel → 17 dB
+/// assert!((processing_gain_db(1e9, 10e6) - 16.99).abs() < 0.01);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `sample_rate` or `bandwidth` is not positive.
+#[must_use]
+pub fn processing_gain_db(sample_rate: f64, bandwidth: f64) -> f64 {
+    assert!(sample_rate > 0.0, "sample rate must be positive");
+    assert!(bandwidth > 0.0, "bandwidth must be positive");
+    linear_to_db(sample_rate / (2.0 * bandwidth))
+}
+
+/// Equivalent noise figure (dB) of a converter: its input-referred noise
+/// density relative to kT₀ (−174 dBm/Hz).
+///
+/// NF = P_FS − SNR − 10·log₁₀(fₛ / 2) − 10·log₁₀(kT₀ · 1000)
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::adc::equivalent_noise_figure;
+/// // +4 dBm full scale, 70 dB SNR, 1 GS/s → ~21 dB
+/// let nf = equivalent_noise_figure(4.0, 70.0, 1e9);
+/// assert!((nf - 20.99).abs() < 0.01);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `sample_rate` is not positive.
+#[must_use]
+pub fn equivalent_noise_figure(full_scale_dbm: f64, snr_db: f64, sample_rate: f64) -> f64 {
+    noise_spectral_density_dbm_per_hz(snr_db, sample_rate, full_scale_dbm)
+        - noise_density_dbm_per_hz(T0)
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn enob_round_trips_ideal_snr() {
+        for bits in [8, 12, 16] {
+            let e = super::enob(super::ideal_snr_db(bits));
+            assert!((e - bits as f64).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn dbfs_round_trip() {
+        let dbm = super::dbfs_to_dbm(super::dbm_to_dbfs(-37.5, 2.0), 2.0);
+        assert!((dbm - (-37.5)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn full_scale_of_2vpp_into_50_ohms() {
+        // 2 Vpp → 0.707 Vrms → 10 mW
+        assert!((super::full_scale_dbm(2.0, 50.0) - 10.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn doubling_sample_rate_lowers_nsd_by_3db() {
+        let a = super::noise_spectral_density_dbfs_per_hz(65.0, 500e6);
+        let b = super::noise_spectral_density_dbfs_per_hz(65.0, 1e9);
+        assert!((a - b - 3.0103).abs() < 1e-4);
+    }
+
+    #[test]
+    fn processing_gain_recovers_in_band_snr() {
+        // SNR in a 1 MHz channel = SNR_nyquist + PG
+        let nsd = super::noise_spectral_density_dbfs_per_hz(70.0, 100e6);
+        let in_band = -(nsd + 60.0);
+        assert!((in_band - (70.0 + super::processing_gain_db(100e6, 1e6))).abs() < 1e-9);
+    }
+
+    #[test]
+    #[should_panic(expected = "sample rate must be positive")]
+    fn rejects_zero_sample_rate() {
+        let _ = super::processing_gain_db(0.0, 1e6);
+    }
+}
@@ -0,0 +1,640 @@
+use crate::power::{db_to_linear, dbm_to_milliwatts, milliwatts_to_dbm};
+
+/// Lowest and highest input power (dBm) searched for compression and
+/// saturation.
+const SEARCH_RANGE_DBM: (f64, f64) = (-150.0, 100.0);
+/// Input step (dB) of the coarse compression and saturation search.
+const SEARCH_STEP_DB: f64 = 0.1;
+
+/// Memoryless power amplifier behavioral model.
+///
+/// The models act on the envelope amplitude r = √P with P in milliwatts, so
+/// r = 1 is 0 dBm. AM/AM is the output amplitude A(r) and AM/PM the phase
+/// shift Φ(r) in radians.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Model {
+    /// Rapp solid-state model (no AM/PM):
+    /// A(r) = G·r / (1 + (G·r / A_sat)^(2p))^(1/(2p)).
+    Rapp {
+        /// Small-signal gain in dB.
+        gain_db: f64,
+        /// Saturated output power in dBm.
+        saturation_dbm: f64,
+        /// Knee smoothness p; larger is sharper.
+        smoothness: f64,
+    },
+    /// Saleh TWT model:
+    /// A(r) = αₐ·r / (1 + βₐ·r²), Φ(r) = α_φ·r² / (1 + β_φ·r²).
+    Saleh {
+        /// AM/AM gain parameter αₐ.
+        alpha_a: f64,
+        /// AM/AM compression parameter βₐ (1/mW).
+        beta_a: f64,
+        /// AM/PM parameter α_φ (rad/mW).
+        alpha_phi: f64,
+        /// AM/PM parameter β_φ (1/mW).
+        beta_phi: f64,
+    },
+    /// Ghorbani SSPA model:
+    /// A(r) = x₁·r^x₂ / (1 + x₃·r^x₂) + x₄·r, Φ(r) = y₁·r^y₂ / (1 + y₃·r^y₂) + y₄·r.
+    ///
+    /// There is no `fit_ghorbani`: the four AM/AM parameters trade off
+    /// against each other on a power sweep, and the AM/PM half needs phase
+    /// data the fitters do not take.
+    Ghorbani {
+        /// AM/AM parameters x₁..x₄.
+        x: [f64; 4],
+        /// AM/PM parameters y₁..y₄.
+        y: [f64; 4],
+    },
+    /// Third-order polynomial A(r) = a₁·r − a₃·r³, held at its peak beyond
+    /// the turning point (no AM/PM).
+    Cubic {
+        /// Small-signal gain in dB.
+        gain_db: f64,
+        /// Output 1 dB compression point in dBm.
+        output_p1db_dbm: f64,
+    },
+}
+
+impl Model {
+    /// Saleh model with a given small-signal gain and saturated output power
+    /// and no AM/PM.
+    ///
+    /// αₐ = √G, βₐ = G / (4·P_sat)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfconversions::amplifier::Model;
+    /// let pa = Model::saleh(20.0, 30.0);
+    /// assert!((pa.saturated_power_dbm() - 30.0).abs() < 1e-6);
+    /// ```
+    #[must_use]
+    pub fn saleh(gain_db: f64, saturation_dbm: f64) -> Self {
+        let gain = db_to_linear(gain_db);
+        Model::Saleh {
+            alpha_a: gain.sqrt(),
+            beta_a: gain / (4.0 * dbm_to_milliwatts(saturation_dbm)),
+            alpha_phi: 0.0,
+            beta_phi: 0.0,
+        }
+    }
+
+    /// Output power (dBm) at an input power (dBm).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfconversions::amplifier::Model;
+    /// let pa = Model::Rapp { gain_db: 20.0, saturation_dbm: 30.0, smoothness: 2.0 };
+    /// // Linear well below saturation
+    /// assert!((pa.output_dbm(-30.0) - (-10.0)).abs() < 1e-6);
+    /// // Approaches P_sat when overdriven
+    /// assert!((pa.output_dbm(30.0) - 30.0).abs() < 1e-3);
+    /// ```
+    #[must_use]
+    pub fn output_dbm(&self, input_dbm: f64) -> f64 {
+        let r = dbm_to_milliwatts(input_dbm).sqrt();
+        let a = self.am_am(r);
+        milliwatts_to_dbm(a * a)
+    }
+
+    /// AM/PM phase shift (degrees) at an input power (dBm).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfconversions::amplifier::Model;
+    /// // Saleh's published TWT parameters at r = 1 (0 dBm)
+    /// let twt = Model::Saleh {
+    ///     alpha_a: 2.1587,
+    ///     beta_a: 1.1517,
+    ///     alpha_phi: 4.0033,
+    ///     beta_phi: 9.1040,
+    /// };
+    /// assert!((twt.phase_deg(0.0) - 22.71).abs() < 0.01);
+    /// ```
+    #[doc(alias = "AM/PM")]
+    #[must_use]
+    pub fn phase_deg(&self, input_dbm: f64) -> f64 {
+        let r = dbm_to_milliwatts(input_dbm).sqrt();
+        let phi = match *self {
+            Model::Saleh {
+                alpha_phi,
+                beta_phi,
+                ..
+            } => alpha_phi * r * r / (1.0 + beta_phi * r * r),
+            Model::Ghorbani { y, .. } => {
+                let ry = r.powf(y[1]);
+                y[0] * ry / (1.0 + y[2] * ry) + y[3] * r
+            }
+            Model::Rapp { .. } | Model::Cubic { .. } => 0.0,
+        };
+        phi.to_degrees()
+    }
+
+    /// Gain (dB) at an input power (dBm).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfconversions::amplifier::Model;
+    /// let pa = Model::Cubic { gain_db: 15.0, output_p1db_dbm: 25.0 };
+    /// // 1 dB compressed at OP1dB − G + 1
+    /// assert!((pa.gain_db(11.0) - 14.0).abs() < 1e-9);
+    /// ```
+    #[must_use]
+    pub fn gain_db(&self, input_dbm: f64) -> f64 {
+        self.output_dbm(input_dbm) - input_dbm
+    }
+
+    /// Small-signal gain (dB).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfconversions::amplifier::Model;
+    /// assert!((Model::saleh(25.0, 40.0).small_signal_gain_db() - 25.0).abs() < 1e-9);
+    /// ```
+    #[must_use]
+    pub fn small_signal_gain_db(&self) -> f64 {
+        match *self {
+            Model::Rapp { gain_db, .. } | Model::Cubic { gain_db, .. } => gain_db,
+            Model::Saleh { alpha_a, .. } => 20.0 * alpha_a.log10(),
+            Model::Ghorbani { .. } => self.gain_db(SEARCH_RANGE_DBM.0),
+        }
+    }
+
+    /// Input and output power `(input_dbm, output_dbm)` at which the gain
+    /// has dropped by `compression_db` from small signal, or `None` if the
+    /// model never compresses that far.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfconversions::amplifier::Model;
+    /// let pa = Model::Rapp { gain_db: 20.0, saturation_dbm: 30.0, smoothness: 2.0 };
+    /// let (pin, pout) = pa.compression_point(2.0).unwrap();
+    /// assert!((pout - pin - 18.0).abs() < 1e-9);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `compression_db` is not positive.
+    #[must_use]
+    pub fn compression_point(&self, compression_db: f64) -> Option<(f64, f64)> {
+        assert!(compression_db > 0.0, "compression must be positive");
+        let target = self.small_signal_gain_db() - compression_db;
+        let compressed = |pin: f64| self.gain_db(pin) <= target;
+
+        let (start, stop) = SEARCH_RANGE_DBM;
+        let steps = ((stop - start) / SEARCH_STEP_DB) as usize;
+        let hi = (1..=steps)
+            .map(|i| start + i as f64 * SEARCH_STEP_DB)
+            .find(|&pin| compressed(pin))?;
+
+        let (mut lo, mut hi) = (hi - SEARCH_STEP_DB, hi);
+        for _ in 0..60 {
+            let mid = 0.5 * (lo + hi);
+            if compressed(mid) {
+                hi = mid;
+            } else {
+                lo = mid;
+            }
+        }
+        Some((hi, self.output_dbm(hi)))
+    }
+
+    /// 1 dB compression point `(input_dbm, output_dbm)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfconversions::amplifier::Model;
+    /// let pa = Model::Cubic { gain_db: 15.0, output_p1db_dbm: 25.0 };
+    /// let (ip1db, op1db) = pa.p1db().unwrap();
+    /// assert!((op1db - 25.0).abs() < 1e-9);
+    /// assert!((ip1db - 11.0).abs() < 1e-9);
+    /// ```
+    #[doc(alias = "P1dB")]
+    #[must_use]
+    pub fn p1db(&self) -> Option<(f64, f64)> {
+        self.compression_point(1.0)
+    }
+
+    /// 3 dB compression point `(input_dbm, output_dbm)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfconversions::amplifier::Model;
+    /// let pa = Model::saleh(20.0, 30.0);
+    /// let (_, op3db) = pa.p3db().unwrap();
+    /// assert!(op3db < 30.0);
+    /// ```
+    #[doc(alias = "P3dB")]
+    #[must_use]
+    pub fn p3db(&self) -> Option<(f64, f64)> {
+        self.compression_point(3.0)
+    }
+
+    /// Saturated (maximum) output power in dBm over the search range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfconversions::amplifier::Model;
+    /// let pa = Model::Cubic { gain_db: 15.0, output_p1db_dbm: 25.0 };
+    /// // A cubic saturates 2.34 dB above its OP1dB
+    /// assert!((pa.saturated_power_dbm() - 27.34).abs() < 0.01);
+    /// ```
+    #[doc(alias = "Psat")]
+    #[must_use]
+    pub fn saturated_power_dbm(&self) -> f64 {
+        let (start, stop) = SEARCH_RANGE_DBM;
+        let steps = ((stop - start) / SEARCH_STEP_DB) as usize;
+        let (peak, _) = (0..=steps)
+            .map(|i| start + i as f64 * SEARCH_STEP_DB)
+            .map(|pin| (pin, self.output_dbm(pin)))
+            .fold((start, f64::NEG_INFINITY), |best, p| {
+                if p.1 > best.1 {
+                    p
+                } else {
+                    best
+                }
+            });
+
+        // Golden-section refinement around the coarse peak.
+        let ratio = (5.0_f64.sqrt() - 1.0) / 2.0;
+        let (mut a, mut b) = (
+            (peak - SEARCH_STEP_DB).max(start),
+            (peak + SEARCH_STEP_DB).min(stop),
+        );
+        for _ in 0..60 {
+            let c = b - ratio * (b - a);
+            let d = a + ratio * (b - a);
+            if self.output_dbm(c) > self.output_dbm(d) {
+                b = d;
+            } else {
+                a = c;
+            }
+        }
+        self.output_dbm(0.5 * (a + b))
+    }
+
+    /// Output envelope amplitude A(r) in √mW.
+    fn am_am(&self, r: f64) -> f64 {
+        match *self {
+            Model::Rapp {
+                gain_db,
+                saturation_dbm,
+                smoothness,
+            } => {
+                let linear = db_to_linear(gain_db).sqrt() * r;
+                let saturation = dbm_to_milliwatts(saturation_dbm).sqrt();
+                let p2 = 2.0 * smoothness;
+                linear / (1.0 + (linear / saturation).powf(p2)).powf(1.0 / p2)
+            }
+            Model::Saleh {
+                alpha_a, beta_a, ..
+            } => alpha_a * r / (1.0 + beta_a * r * r),
+            Model::Ghorbani { x, .. } => {
+                let rx = r.powf(x[1]);
+                (x[0] * rx / (1.0 + x[2] * rx) + x[3] * r).abs()
+            }
+            Model::Cubic {
+                gain_db,
+                output_p1db_dbm,
+            } => {
+                let a1 = db_to_linear(gain_db).sqrt();
+                let compressed = db_to_linear(-1.0).sqrt();
+                // Input amplitude at P1dB, where a₁·r·(1 − (a₃/a₁)·r²) is 1 dB low.
+                let r1 = dbm_to_milliwatts(output_p1db_dbm).sqrt() / (a1 * compressed);
+                let a3 = a1 * (1.0 - compressed) / (r1 * r1);
+                let r = r.min((a1 / (3.0 * a3)).sqrt());
+                a1 * r - a3 * r * r * r
+            }
+        }
+    }
+}
+
+/// Gain compression (dB) of a model relative to its small-signal gain at
+/// an input power.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::amplifier::{gain_compression_db, Model};
+/// let pa = Model::Cubic { gain_db: 15.0, output_p1db_dbm: 25.0 };
+/// assert!((gain_compression_db(&pa, 11.0) - 1.0).abs() < 1e-9);
+/// ```
+#[must_use]
+pub fn gain_compression_db(model: &Model, input_dbm: f64) -> f64 {
+    model.small_signal_gain_db() - model.gain_db(input_dbm)
+}
+
+/// Output backoff (dB) from saturation at an input power.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::amplifier::{output_backoff_db, Model};
+/// let pa = Model::saleh(20.0, 30.0);
+/// assert!(output_backoff_db(&pa, -20.0) > 9.0);
+/// ```
+#[doc(alias = "OBO")]
+#[must_use]
+pub fn output_backoff_db(model: &Model, input_dbm: f64) -> f64 {
+    model.saturated_power_dbm() - model.output_dbm(input_dbm)
+}
+
+/// Fit a Rapp model to measured `(input_dbm, output_dbm)` pairs by least
+/// squares on the output power in dB.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::amplifier::{fit_rapp, Model};
+/// let truth = Model::Rapp { gain_db: 28.0, saturation_dbm: 37.0, smoothness: 1.5 };
+/// let sweep: Vec<(f64, f64)> = (-30..=10)
+///     .map(|p| (p as f64, truth.output_dbm(p as f64)))
+///     .collect();
+/// let Model::Rapp { gain_db, saturation_dbm, smoothness } = fit_rapp(&sweep) else {
+///     panic!("fit_rapp should return a Rapp model");
+/// };
+/// assert!((gain_db - 28.0).abs() < 1e-3);
+/// assert!((saturation_dbm - 37.0).abs() < 1e-3);
+/// assert!((smoothness - 1.5).abs() < 1e-2);
+/// ```
+///
+/// # Panics
+///
+/// Panics if fewer than three measurements are given.
+#[must_use]
+pub fn fit_rapp(measurements: &[(f64, f64)]) -> Model {
+    let (gain, peak) = initial_guess(measurements);
+    let rapp = |p: &[f64]| Model::Rapp {
+        gain_db: p[0],
+        saturation_dbm: p[1],
+        smoothness: p[2].exp(),
+    };
+    let p = fit(measurements, vec![gain, peak + 1.0, 2.0_f64.ln()], rapp);
+    rapp(&p)
+}
+
+/// Fit a Saleh AM/AM model (no AM/PM) to measured `(input_dbm, output_dbm)`
+/// pairs.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::amplifier::{fit_saleh, Model};
+/// let truth = Model::saleh(22.0, 33.0);
+/// let sweep: Vec<(f64, f64)> = (-30..=5)
+///     .map(|p| (p as f64, truth.output_dbm(p as f64)))
+///     .collect();
+/// let fitted = fit_saleh(&sweep);
+/// assert!((fitted.small_signal_gain_db() - 22.0).abs() < 1e-3);
+/// assert!((fitted.saturated_power_dbm() - 33.0).abs() < 1e-3);
+/// ```
+///
+/// # Panics
+///
+/// Panics if fewer than three measurements are given.
+#[must_use]
+pub fn fit_saleh(measurements: &[(f64, f64)]) -> Model {
+    let (gain, peak) = initial_guess(measurements);
+    let saleh = |p: &[f64]| Model::saleh(p[0], p[1]);
+    let p = fit(measurements, vec![gain, peak + 1.0], saleh);
+    saleh(&p)
+}
+
+/// Fit a cubic polynomial model to measured `(input_dbm, output_dbm)` pairs.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::amplifier::{fit_cubic, Model};
+/// let truth = Model::Cubic { gain_db: 12.0, output_p1db_dbm: 20.0 };
+/// let sweep: Vec<(f64, f64)> = (-20..=8)
+///     .map(|p| (p as f64, truth.output_dbm(p as f64)))
+///     .collect();
+/// let Model::Cubic { gain_db, output_p1db_dbm } = fit_cubic(&sweep) else {
+///     panic!("fit_cubic should return a cubic model");
+/// };
+/// assert!((gain_db - 12.0).abs() < 1e-3);
+/// assert!((output_p1db_dbm - 20.0).abs() < 1e-3);
+/// ```
+///
+/// # Panics
+///
+/// Panics if fewer than three measurements are given.
+#[must_use]
+pub fn fit_cubic(measurements: &[(f64, f64)]) -> Model {
+    let (gain, peak) = initial_guess(measurements);
+    let cubic = |p: &[f64]| Model::Cubic {
+        gain_db: p[0],
+        output_p1db_dbm: p[1],
+    };
+    let p = fit(measurements, vec![gain, peak - 1.0], cubic);
+    cubic(&p)
+}
+
+/// Starting point for a fit: the gain at the lowest drive and the highest
+/// measured output.
+fn initial_guess(measurements: &[(f64, f64)]) -> (f64, f64) {
+    assert!(
+        measurements.len() >= 3,
+        "at least three measurements are required"
+    );
+    let lowest = measurements
+        .iter()
+        .min_by(|a, b| a.0.total_cmp(&b.0))
+        .expect("measurements are not empty");
+    let peak = measurements
+        .iter()
+        .map(|&(_, pout)| pout)
+        .fold(f64::NEG_INFINITY, f64::max);
+    (lowest.1 - lowest.0, peak)
+}
+
+/// Least-squares fit of model parameters on the output power in dB.
+fn fit(measurements: &[(f64, f64)], start: Vec<f64>, model: impl Fn(&[f64]) -> Model) -> Vec<f64> {
+    let cost = |p: &[f64]| {
+        let m = model(p);
+        measurements
+            .iter()
+            .map(|&(pin, pout)| (m.output_dbm(pin) - pout).powi(2))
+            .sum::<f64>()
+    };
+    // Restart once from the first result to escape a collapsed simplex.
+    let p = nelder_mead(&cost, start, 1.0);
+    nelder_mead(&cost, p, 0.1)
+}
+
+/// Nelder–Mead simplex minimisation.
+fn nelder_mead(f: &impl Fn(&[f64]) -> f64, start: Vec<f64>, step: f64) -> Vec<f64> {
+    let n = start.len();
+    let mut simplex: Vec<(Vec<f64>, f64)> = (0..=n)
+        .map(|i| {
+            let mut x = start.clone();
+            if i > 0 {
+                x[i - 1] += step;
+            }
+            let fx = f(&x);
+            (x, fx)
+        })
+        .collect();
+
+    for _ in 0..5000 {
+        simplex.sort_by(|a, b| a.1.total_cmp(&b.1));
+        if (simplex[n].1 - simplex[0].1).abs() < 1e-24 {
+            break;
+        }
+
+        let centroid: Vec<f64> = (0..n)
+            .map(|j| simplex[..n].iter().map(|(x, _)| x[j]).sum::<f64>() / n as f64)
+            .collect();
+        let along = |t: f64| -> Vec<f64> {
+            centroid
+                .iter()
+                .zip(&simplex[n].0)
+                .map(|(c, w)| c + t * (w - c))
+                .collect()
+        };
+
+        let reflected = along(-1.0);
+        let fr = f(&reflected);
+        if fr < simplex[0].1 {
+            let expanded = along(-2.0);
+            let fe = f(&expanded);
+            simplex[n] = if fe < fr {
+                (expanded, fe)
+            } else {
+                (reflected, fr)
+            };
+        } else if fr < simplex[n - 1].1 {
+            simplex[n] = (reflected, fr);
+        } else {
+            let contracted = along(0.5);
+            let fc = f(&contracted);
+            if fc < simplex[n].1 {
+                simplex[n] = (contracted, fc);
+            } else {
+                let best = simplex[0].0.clone();
+                for (x, fx) in simplex.iter_mut().skip(1) {
+                    for (xi, bi) in x.iter_mut().zip(&best) {
+                        *xi = bi + 0.5 * (*xi - bi);
+                    }
+                    *fx = f(x);
+                }
+            }
+        }
+    }
+
+    simplex
+        .into_iter()
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .expect("simplex is not empty")
+        .0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Model;
+
+    const RAPP: Model = Model::Rapp {
+        gain_db: 20.0,
+        saturation_dbm: 30.0,
+        smoothness: 2.0,
+    };
+
+    #[test]
+    fn rapp_p1db_matches_closed_form() {
+        // (G·r/A_sat)^(2p) = 10^(p/10) − 1 at 1 dB compression
+        let p: f64 = 2.0;
+        let x = (10.0_f64.powf(p / 10.0) - 1.0).powf(1.0 / (2.0 * p));
+        let expected_input = 30.0 + 20.0 * x.log10() - 20.0;
+        let (pin, pout) = RAPP.p1db().unwrap();
+        assert!((pin - expected_input).abs() < 1e-9);
+        assert!((pout - (pin + 19.0)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn saleh_saturates_at_its_turning_point() {
+        // Peak at r = 1/√βₐ, output αₐ²/(4βₐ)
+        let (alpha_a, beta_a) = (2.1587_f64, 1.1517_f64);
+        let twt = Model::Saleh {
+            alpha_a,
+            beta_a,
+            alpha_phi: 0.0,
+            beta_phi: 0.0,
+        };
+        let expected = crate::power::linear_to_db(alpha_a * alpha_a / (4.0 * beta_a));
+        assert!((twt.saturated_power_dbm() - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn ghorbani_matches_formula() {
+        let pa = Model::Ghorbani {
+            x: [8.1081, 1.5413, 6.5202, -0.0718],
+            y: [4.6645, 2.0965, 10.88, -0.003],
+        };
+        let r: f64 = 0.5;
+        let rx = r.powf(1.5413);
+        let a = 8.1081 * rx / (1.0 + 6.5202 * rx) - 0.0718 * r;
+        let pin = crate::power::linear_to_db(r * r);
+        assert!((pa.output_dbm(pin) - crate::power::linear_to_db(a * a)).abs() < 1e-9);
+        let ry = r.powf(2.0965);
+        let phi = 4.6645 * ry / (1.0 + 10.88 * ry) - 0.003 * r;
+        assert!((pa.phase_deg(pin) - phi.to_degrees()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn cubic_holds_its_peak() {
+        let pa = Model::Cubic {
+            gain_db: 15.0,
+            output_p1db_dbm: 25.0,
+        };
+        let psat = pa.saturated_power_dbm();
+        assert!((pa.output_dbm(40.0) - psat).abs() < 1e-9);
+    }
+
+    #[test]
+    fn p3db_is_beyond_p1db() {
+        let (in1, out1) = RAPP.p1db().unwrap();
+        let (in3, out3) = RAPP.p3db().unwrap();
+        assert!(in3 > in1 && out3 > out1 && out3 < 30.0);
+    }
+
+    #[test]
+    fn linear_model_never_compresses() {
+        let pa = Model::Rapp {
+            gain_db: 10.0,
+            saturation_dbm: 200.0,
+            smoothness: 2.0,
+        };
+        assert!(pa.p1db().is_none());
+    }
+
+    #[test]
+    fn fit_rapp_with_noise_stays_close() {
+        let sweep: Vec<(f64, f64)> = (-20..=15)
+            .map(|p| {
+                let pin = p as f64;
+                let wiggle = if p % 2 == 0 { 0.02 } else { -0.02 };
+                (pin, RAPP.output_dbm(pin) + wiggle)
+            })
+            .collect();
+        let fitted = super::fit_rapp(&sweep);
+        assert!((fitted.small_signal_gain_db() - 20.0).abs() < 0.05);
+        assert!((fitted.saturated_power_dbm() - 30.0).abs() < 0.1);
+    }
+
+    #[test]
+    #[should_panic(expected = "at least three measurements are required")]
+    fn fit_rejects_short_sweep() {
+        let _ = super::fit_cubic(&[(0.0, 10.0), (1.0, 11.0)]);
+    }
+}
@@ -0,0 +1,660 @@
+/// Standard E-series resistor value sets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResistorSeries {
+    /// 24 values per decade (5 % tolerance).
+    E24,
+    /// 96 values per decade (1 % tolerance).
+    E96,
+}
+
+/// E24 mantissas ×10.
+const E24: [u16; 24] = [
+    10, 11, 12, 13, 15, 16, 18, 20, 22, 24, 27, 30, 33, 36, 39, 43, 47, 51, 56, 62, 68, 75, 82, 91,
+];
+
+/// E96 mantissas ×100.
+const E96: [u16; 96] = [
+    100, 102, 105, 107, 110, 113, 115, 118, 121, 124, 127, 130, //
+    133, 137, 140, 143, 147, 150, 154, 158, 162, 165, 169, 174, //
+    178, 182, 187, 191, 196, 200, 205, 210, 215, 221, 226, 232, //
+    237, 243, 249, 255, 261, 267, 274, 280, 287, 294, 301, 309, //
+    316, 324, 332, 340, 348, 357, 365, 374, 383, 392, 402, 412, //
+    422, 432, 442, 453, 464, 475, 487, 499, 511, 523, 536, 549, //
+    562, 576, 590, 604, 619, 634, 649, 665, 681, 698, 715, 732, //
+    750, 768, 787, 806, 825, 845, 866, 887, 909, 931, 953, 976, //
+];
+
+/// Resistor values (ohms) of a matched resistive attenuator, listed from
+/// source to load.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Pad {
+    /// Shunt – series – shunt.
+    Pi {
+        /// Shunt resistor across the input.
+        shunt_input: f64,
+        /// Series resistor between input and output.
+        series: f64,
+        /// Shunt resistor across the output.
+        shunt_output: f64,
+    },
+    /// Series – shunt – series.
+    T {
+        /// Series resistor at the input.
+        series_input: f64,
+        /// Shunt resistor at the center node.
+        shunt: f64,
+        /// Series resistor at the output.
+        series_output: f64,
+    },
+    /// Two series arms with a bridging resistor across them and a shunt
+    /// resistor from their junction to ground.
+    BridgedT {
+        /// Series arm from the input to the center node.
+        series_input: f64,
+        /// Series arm from the center node to the output.
+        series_output: f64,
+        /// Bridging resistor from input to output.
+        bridge: f64,
+        /// Shunt resistor from the center node to ground.
+        shunt: f64,
+    },
+}
+
+impl Pad {
+    /// Resistor values in source-to-load order.
+    ///
+    /// The order matches the variant's field order, and is the order used by
+    /// [`pad_dissipation`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfconversions::attenuator::Pad;
+    /// let pad = Pad::T { series_input: 26.0, shunt: 35.0, series_output: 26.0 };
+    /// assert_eq!(pad.resistances(), vec![26.0, 35.0, 26.0]);
+    /// ```
+    #[must_use]
+    pub fn resistances(&self) -> Vec<f64> {
+        match *self {
+            Pad::Pi {
+                shunt_input,
+                series,
+                shunt_output,
+            } => vec![shunt_input, series, shunt_output],
+            Pad::T {
+                series_input,
+                shunt,
+                series_output,
+            } => vec![series_input, shunt, series_output],
+            Pad::BridgedT {
+                series_input,
+                series_output,
+                bridge,
+                shunt,
+            } => vec![series_input, series_output, bridge, shunt],
+        }
+    }
+
+    /// Apply `f` to every resistor, keeping the topology.
+    fn map(&self, f: impl Fn(f64) -> f64) -> Pad {
+        match *self {
+            Pad::Pi {
+                shunt_input,
+                series,
+                shunt_output,
+            } => Pad::Pi {
+                shunt_input: f(shunt_input),
+                series: f(series),
+                shunt_output: f(shunt_output),
+            },
+            Pad::T {
+                series_input,
+                shunt,
+                series_output,
+            } => Pad::T {
+                series_input: f(series_input),
+                shunt: f(shunt),
+                series_output: f(series_output),
+            },
+            Pad::BridgedT {
+                series_input,
+                series_output,
+                bridge,
+                shunt,
+            } => Pad::BridgedT {
+                series_input: f(series_input),
+                series_output: f(series_output),
+                bridge: f(bridge),
+                shunt: f(shunt),
+            },
+        }
+    }
+}
+
+/// A minimum-loss pad matching two unequal resistances.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MinimumLossPad {
+    /// Series resistor on the higher-impedance side, in ohms.
+    pub series: f64,
+    /// Shunt resistor on the lower-impedance side, in ohms.
+    pub shunt: f64,
+    /// Insertion loss of the pad in dB.
+    pub loss_db: f64,
+}
+
+/// Voltage ratio K = 10^(A/20) of an attenuation in dB.
+fn voltage_ratio(attenuation_db: f64) -> f64 {
+    assert!(attenuation_db > 0.0, "attenuation must be positive");
+    crate::power::db_to_linear(attenuation_db).sqrt()
+}
+
+/// Design a matched Pi attenuator.
+///
+/// With K = 10^(A/20): R_shunt = Z₀(K + 1)/(K − 1), R_series = Z₀(K² − 1)/(2K).
+///
+/// # Panics
+///
+/// Panics if `attenuation_db` is not positive.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::attenuator::{pi_pad, Pad};
+/// let Pad::Pi { shunt_input, series, .. } = pi_pad(10.0, 50.0) else { unreachable!() };
+/// assert!((shunt_input - 96.25).abs() < 0.01);
+/// assert!((series - 71.15).abs() < 0.01);
+/// ```
+#[doc(alias = "Pi attenuator")]
+#[must_use]
+pub fn pi_pad(attenuation_db: f64, impedance: f64) -> Pad {
+    let k = voltage_ratio(attenuation_db);
+    let shunt = impedance * (k + 1.0) / (k - 1.0);
+    Pad::Pi {
+        shunt_input: shunt,
+        series: impedance * (k * k - 1.0) / (2.0 * k),
+        shunt_output: shunt,
+    }
+}
+
+/// Design a matched T attenuator.
+///
+/// With K = 10^(A/20): R_series = Z₀(K − 1)/(K + 1), R_shunt = 2Z₀K/(K² − 1).
+///
+/// # Panics
+///
+/// Panics if `attenuation_db` is not positive.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::attenuator::{t_pad, Pad};
+/// let Pad::T { series_input, shunt, .. } = t_pad(10.0, 50.0) else { unreachable!() };
+/// assert!((series_input - 25.97).abs() < 0.01);
+/// assert!((shunt - 35.14).abs() < 0.01);
+/// ```
+#[doc(alias = "T attenuator")]
+#[doc(alias = "Tee")]
+#[must_use]
+pub fn t_pad(attenuation_db: f64, impedance: f64) -> Pad {
+    let k = voltage_ratio(attenuation_db);
+    let series = impedance * (k - 1.0) / (k + 1.0);
+    Pad::T {
+        series_input: series,
+        shunt: impedance * 2.0 * k / (k * k - 1.0),
+        series_output: series,
+    }
+}
+
+/// Design a matched bridged-T attenuator.
+///
+/// Both series arms equal Z₀; with K = 10^(A/20):
+/// R_bridge = Z₀(K − 1), R_shunt = Z₀/(K − 1).
+///
+/// # Panics
+///
+/// Panics if `attenuation_db` is not positive.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::attenuator::{bridged_t_pad, Pad};
+/// let Pad::BridgedT { bridge, shunt, .. } = bridged_t_pad(10.0, 50.0) else { unreachable!() };
+/// assert!((bridge - 108.11).abs() < 0.01);
+/// assert!((shunt - 23.12).abs() < 0.01);
+/// ```
+#[doc(alias = "bridged tee")]
+#[must_use]
+pub fn bridged_t_pad(attenuation_db: f64, impedance: f64) -> Pad {
+    let k = voltage_ratio(attenuation_db);
+    Pad::BridgedT {
+        series_input: impedance,
+        series_output: impedance,
+        bridge: impedance * (k - 1.0),
+        shunt: impedance / (k - 1.0),
+    }
+}
+
+/// Design a minimum-loss L pad matching resistances `z1` and `z2`.
+///
+/// With Z_high > Z_low: R_series = √(Z_high(Z_high − Z_low)),
+/// R_shunt = Z_low·√(Z_high/(Z_high − Z_low)), and the loss is
+/// 20·log₁₀(√(Z_high/Z_low) + √(Z_high/Z_low − 1)) dB.
+///
+/// # Panics
+///
+/// Panics if `z1` equals `z2`.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::attenuator::minimum_loss_pad;
+/// // 75 Ω ↔ 50 Ω
+/// let pad = minimum_loss_pad(75.0, 50.0);
+/// assert!((pad.series - 43.30).abs() < 0.01);
+/// assert!((pad.shunt - 86.60).abs() < 0.01);
+/// assert!((pad.loss_db - 5.72).abs() < 0.01);
+/// ```
+#[doc(alias = "impedance matching pad")]
+#[must_use]
+pub fn minimum_loss_pad(z1: f64, z2: f64) -> MinimumLossPad {
+    assert!(z1 != z2, "impedances must differ");
+    let high = z1.max(z2);
+    let low = z1.min(z2);
+    let ratio = high / low;
+    MinimumLossPad {
+        series: (high * (high - low)).sqrt(),
+        shunt: low * (high / (high - low)).sqrt(),
+        loss_db: 20.0 * (ratio.sqrt() + (ratio - 1.0).sqrt()).log10(),
+    }
+}
+
+/// Nearest standard resistor value (ohms) in an E-series.
+///
+/// Nearness is judged on a logarithmic scale, matching how the series are spaced.
+///
+/// # Panics
+///
+/// Panics if `resistance` is not positive and finite.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::attenuator::{nearest_standard_resistor, ResistorSeries};
+/// assert_eq!(nearest_standard_resistor(96.25, ResistorSeries::E24), 100.0);
+/// assert_eq!(nearest_standard_resistor(96.25, ResistorSeries::E96), 95.3);
+/// ```
+#[doc(alias = "E24")]
+#[doc(alias = "E96")]
+#[must_use]
+pub fn nearest_standard_resistor(resistance: f64, series: ResistorSeries) -> f64 {
+    assert!(
+        resistance.is_finite() && resistance > 0.0,
+        "resistance must be positive and finite"
+    );
+
+    let (table, scale): (&[u16], f64) = match series {
+        ResistorSeries::E24 => (&E24, 10.0),
+        ResistorSeries::E96 => (&E96, 100.0),
+    };
+
+    let decade = 10.0_f64.powf(resistance.log10().floor());
+    let mantissa = resistance / decade;
+    let candidates = table
+        .iter()
+        .map(|&v| f64::from(v) / scale)
+        .chain(std::iter::once(10.0));
+
+    let mut best = 1.0;
+    for candidate in candidates {
+        if (mantissa / candidate).ln().abs() < (mantissa / best).ln().abs() {
+            best = candidate;
+        }
+    }
+
+    // Round away floating-point noise from the decade scaling
+    let value = best * decade;
+    let digits = 10.0_f64.powi(3 - value.log10().floor() as i32);
+    (value * digits).round() / digits
+}
+
+/// Snap every resistor of `pad` to the nearest value in `series`.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::attenuator::{pi_pad, snap_pad, Pad, ResistorSeries};
+/// let pad = snap_pad(&pi_pad(10.0, 50.0), ResistorSeries::E24);
+/// assert_eq!(pad, Pad::Pi { shunt_input: 100.0, series: 68.0, shunt_output: 100.0 });
+/// ```
+#[must_use]
+pub fn snap_pad(pad: &Pad, series: ResistorSeries) -> Pad {
+    pad.map(|r| nearest_standard_resistor(r, series))
+}
+
+/// Solved operating point of a pad driven with 1 V at its input and
+/// terminated in `load`.
+struct PadSolution {
+    /// Input resistance in ohms.
+    input_resistance: f64,
+    /// Output voltage in volts.
+    output_voltage: f64,
+    /// Dissipation per resistor in watts, in [`Pad::resistances`] order.
+    dissipation: Vec<f64>,
+}
+
+fn parallel(a: f64, b: f64) -> f64 {
+    a * b / (a + b)
+}
+
+fn solve(pad: &Pad, load: f64) -> PadSolution {
+    let (output_voltage, dissipation) = match *pad {
+        Pad::Pi {
+            shunt_input,
+            series,
+            shunt_output,
+        } => {
+            let tail = parallel(shunt_output, load);
+            let v_out = tail / (series + tail);
+            (
+                v_out,
+                vec![
+                    1.0 / shunt_input,
+                    (1.0 - v_out).powi(2) / series,
+                    v_out * v_out / shunt_output,
+                ],
+            )
+        }
+        Pad::T {
+            series_input,
+            shunt,
+            series_output,
+        } => {
+            let tail = parallel(shunt, series_output + load);
+            let i_in = 1.0 / (series_input + tail);
+            let v_center = i_in * tail;
+            let i_out = v_center / (series_output + load);
+            (
+                i_out * load,
+                vec![
+                    i_in * i_in * series_input,
+                    v_center * v_center / shunt,
+                    i_out * i_out * series_output,
+                ],
+            )
+        }
+        Pad::BridgedT {
+            series_input,
+            series_output,
+            bridge,
+            shunt,
+        } => {
+            // Nodal equations for the center node (c) and output node (o):
+            // (1/Ra + 1/Rb + 1/Rsh)·Vc − (1/Rb)·Vo = 1/Ra
+            // −(1/Rb)·Vc + (1/Rbr + 1/Rb + 1/RL)·Vo = 1/Rbr
+            let (ga, gb, gbr, gsh, gl) = (
+                1.0 / series_input,
+                1.0 / series_output,
+                1.0 / bridge,
+                1.0 / shunt,
+                1.0 / load,
+            );
+            let a11 = ga + gb + gsh;
+            let a22 = gbr + gb + gl;
+            let det = a11 * a22 - gb * gb;
+            let v_c = (ga * a22 + gb * gbr) / det;
+            let v_o = (a11 * gbr + gb * ga) / det;
+            (
+                v_o,
+                vec![
+                    (1.0 - v_c).powi(2) * ga,
+                    (v_c - v_o).powi(2) * gb,
+                    (1.0 - v_o).powi(2) * gbr,
+                    v_c * v_c * gsh,
+                ],
+            )
+        }
+    };
+
+    let input_power = dissipation.iter().sum::<f64>() + output_voltage * output_voltage / load;
+    PadSolution {
+        input_resistance: 1.0 / input_power,
+        output_voltage,
+        dissipation,
+    }
+}
+
+/// Insertion loss (dB) of `pad` between a source and load of `impedance` ohms.
+///
+/// Equals the design attenuation for ideal values; use it to check a pad
+/// after [`snap_pad`].
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::attenuator::{pad_attenuation_db, t_pad};
+/// let pad = t_pad(6.0, 50.0);
+/// assert!((pad_attenuation_db(&pad, 50.0) - 6.0).abs() < 1e-9);
+/// ```
+#[doc(alias = "insertion loss")]
+#[doc(alias = "S21")]
+#[must_use]
+pub fn pad_attenuation_db(pad: &Pad, impedance: f64) -> f64 {
+    let solution = solve(pad, impedance);
+    let r_in = solution.input_resistance;
+    // Source EMF needed for 1 V at the input is (Z₀ + R_in) / R_in
+    let s21 = 2.0 * solution.output_voltage * r_in / (impedance + r_in);
+    -20.0 * s21.log10()
+}
+
+/// Input return loss (dB) of `pad` terminated in `impedance` ohms.
+///
+/// Ideal pads are perfectly matched and return `f64::INFINITY`.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::attenuator::{pad_return_loss_db, pi_pad, snap_pad, ResistorSeries};
+/// let pad = snap_pad(&pi_pad(10.0, 50.0), ResistorSeries::E24);
+/// assert!(pad_return_loss_db(&pad, 50.0) > 30.0);
+/// ```
+#[doc(alias = "S11")]
+#[doc(alias = "RL")]
+#[must_use]
+pub fn pad_return_loss_db(pad: &Pad, impedance: f64) -> f64 {
+    let r_in = solve(pad, impedance).input_resistance;
+    let gamma = ((r_in - impedance) / (r_in + impedance)).abs();
+    -20.0 * gamma.log10()
+}
+
+/// Power (W) dissipated in each resistor of `pad` for `input_power_dbm`
+/// delivered into its input, with the output terminated in `impedance` ohms.
+///
+/// Values are in [`Pad::resistances`] order.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::attenuator::{pad_dissipation, pi_pad};
+/// // 1 W into a 3 dB pad: half the power is burned in the pad
+/// let watts = pad_dissipation(&pi_pad(3.0103, 50.0), 50.0, 30.0);
+/// let total: f64 = watts.iter().sum();
+/// assert!((total - 0.5).abs() < 1e-4);
+/// ```
+#[doc(alias = "power rating")]
+#[must_use]
+pub fn pad_dissipation(pad: &Pad, impedance: f64, input_power_dbm: f64) -> Vec<f64> {
+    let solution = solve(pad, impedance);
+    // The solution is for 1 V at the input, i.e. 1/R_in watts
+    let scale = crate::power::dbm_to_watts(input_power_dbm) * solution.input_resistance;
+    solution.dissipation.iter().map(|p| p * scale).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Pad, ResistorSeries};
+
+    #[test]
+    fn pi_pad_10db_50_ohm() {
+        let Pad::Pi {
+            shunt_input,
+            series,
+            shunt_output,
+        } = super::pi_pad(10.0, 50.0)
+        else {
+            panic!("expected Pi pad");
+        };
+        assert!((shunt_input - 96.248).abs() < 0.001);
+        assert!((series - 71.151).abs() < 0.001);
+        assert_eq!(shunt_input, shunt_output);
+    }
+
+    #[test]
+    fn t_pad_3db_50_ohm() {
+        let Pad::T {
+            series_input,
+            shunt,
+            ..
+        } = super::t_pad(3.0, 50.0)
+        else {
+            panic!("expected T pad");
+        };
+        assert!((series_input - 8.549).abs() < 0.001);
+        assert!((shunt - 141.93).abs() < 0.01);
+    }
+
+    #[test]
+    fn ideal_pads_hit_design_attenuation_and_match() {
+        for db in [1.0, 3.0, 6.0, 10.0, 20.0, 40.0] {
+            for pad in [
+                super::pi_pad(db, 50.0),
+                super::t_pad(db, 50.0),
+                super::bridged_t_pad(db, 50.0),
+            ] {
+                let att = super::pad_attenuation_db(&pad, 50.0);
+                assert!((att - db).abs() < 1e-9, "{pad:?}: {att} dB");
+                assert!(super::pad_return_loss_db(&pad, 50.0) > 100.0);
+            }
+        }
+    }
+
+    #[test]
+    fn pad_scales_with_impedance() {
+        let a = super::pi_pad(6.0, 50.0).resistances();
+        let b = super::pi_pad(6.0, 75.0).resistances();
+        for (ra, rb) in a.iter().zip(&b) {
+            assert!((rb / ra - 1.5).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "attenuation must be positive")]
+    fn zero_attenuation_panics() {
+        let _ = super::pi_pad(0.0, 50.0);
+    }
+
+    #[test]
+    fn minimum_loss_pad_symmetric_arguments() {
+        assert_eq!(
+            super::minimum_loss_pad(75.0, 50.0),
+            super::minimum_loss_pad(50.0, 75.0)
+        );
+    }
+
+    #[test]
+    fn minimum_loss_pad_matches_both_ports() {
+        let pad = super::minimum_loss_pad(75.0, 50.0);
+        // Looking from 75 Ω: series + (shunt || 50)
+        let from_high = pad.series + super::parallel(pad.shunt, 50.0);
+        // Looking from 50 Ω: shunt || (series + 75)
+        let from_low = super::parallel(pad.shunt, pad.series + 75.0);
+        assert!((from_high - 75.0).abs() < 1e-9);
+        assert!((from_low - 50.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn nearest_standard_resistor_e24() {
+        assert_eq!(
+            super::nearest_standard_resistor(71.15, ResistorSeries::E24),
+            68.0
+        );
+        assert_eq!(
+            super::nearest_standard_resistor(4.8, ResistorSeries::E24),
+            4.7
+        );
+        assert_eq!(
+            super::nearest_standard_resistor(9700.0, ResistorSeries::E24),
+            10000.0
+        );
+        assert_eq!(
+            super::nearest_standard_resistor(0.26, ResistorSeries::E24),
+            0.27
+        );
+    }
+
+    #[test]
+    fn nearest_standard_resistor_e96() {
+        assert_eq!(
+            super::nearest_standard_resistor(71.15, ResistorSeries::E96),
+            71.5
+        );
+        assert_eq!(
+            super::nearest_standard_resistor(141.93, ResistorSeries::E96),
+            143.0
+        );
+        assert_eq!(
+            super::nearest_standard_resistor(49.9, ResistorSeries::E96),
+            49.9
+        );
+    }
+
+    #[test]
+    fn nearest_standard_resistor_exact_values_unchanged() {
+        for &v in &super::E96 {
+            let r = f64::from(v) * 10.0;
+            assert_eq!(super::nearest_standard_resistor(r, ResistorSeries::E96), r);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "resistance must be positive and finite")]
+    fn nearest_standard_resistor_rejects_zero() {
+        let _ = super::nearest_standard_resistor(0.0, ResistorSeries::E24);
+    }
+
+    #[test]
+    fn snapped_e96_pad_is_closer_than_e24() {
+        let ideal = super::t_pad(10.0, 50.0);
+        let e24 = super::snap_pad(&ideal, ResistorSeries::E24);
+        let e96 = super::snap_pad(&ideal, ResistorSeries::E96);
+        let err24 = (super::pad_attenuation_db(&e24, 50.0) - 10.0).abs();
+        let err96 = (super::pad_attenuation_db(&e96, 50.0) - 10.0).abs();
+        assert!(err96 < err24);
+        assert!(super::pad_return_loss_db(&e96, 50.0) > super::pad_return_loss_db(&e24, 50.0));
+    }
+
+    #[test]
+    fn dissipation_conserves_power() {
+        for pad in [
+            super::pi_pad(10.0, 50.0),
+            super::t_pad(10.0, 50.0),
+            super::bridged_t_pad(10.0, 50.0),
+        ] {
+            // 10 dBm in, 0 dBm out: 9 mW burned in the pad
+            let total: f64 = super::pad_dissipation(&pad, 50.0, 10.0).iter().sum();
+            assert!((total - 0.009).abs() < 1e-12, "{pad:?}: {total}");
+        }
+    }
+
+    #[test]
+    fn bridged_t_output_arm_carries_no_current_when_matched() {
+        let watts = super::pad_dissipation(&super::bridged_t_pad(10.0, 50.0), 50.0, 30.0);
+        assert!(watts[1].abs() < 1e-15);
+    }
+
+    #[test]
+    fn pi_pad_input_shunt_dissipation() {
+        // 1 W into 10 dB Pi pad: V² = P·Z₀ = 50, input shunt burns 50 / 96.25
+        let watts = super::pad_dissipation(&super::pi_pad(10.0, 50.0), 50.0, 30.0);
+        assert!((watts[0] - 50.0 / 96.248).abs() < 1e-4);
+    }
+}
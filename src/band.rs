@@ -0,0 +1,323 @@
+/// Letter or name designation scheme for frequency bands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scheme {
+    /// IEEE Std 521 radar letter bands (HF through mm).
+    Ieee,
+    /// ITU Radio Regulations bands 1–12 (ELF through THF).
+    Itu,
+    /// NATO / EU electronic-warfare letter bands A–M.
+    Nato,
+    /// Common satellite-communications naming; edges vary by source.
+    Satellite,
+}
+
+impl Scheme {
+    /// Band table for this scheme, ordered by frequency.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfconversions::band::Scheme;
+    /// assert_eq!(Scheme::Nato.bands().len(), 13);
+    /// assert_eq!(Scheme::Itu.bands()[0].name, "ELF");
+    /// ```
+    #[must_use]
+    pub fn bands(&self) -> &'static [Band] {
+        match self {
+            Scheme::Ieee => &IEEE_BANDS,
+            Scheme::Itu => &ITU_BANDS,
+            Scheme::Nato => &NATO_BANDS,
+            Scheme::Satellite => &SATELLITE_BANDS,
+        }
+    }
+}
+
+/// A named frequency band.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Band {
+    /// Designation, e.g. `"Ku"` or `"SHF"`.
+    pub name: &'static str,
+    /// Lower band edge in Hz, inclusive unless `upper_edge_inclusive`.
+    pub min_frequency: f64,
+    /// Upper band edge in Hz, exclusive unless `upper_edge_inclusive`.
+    pub max_frequency: f64,
+    /// Whether the band is `(min, max]` rather than `[min, max)`, as for
+    /// ITU bands (RR No. 2.1).
+    pub upper_edge_inclusive: bool,
+}
+
+impl Band {
+    /// Whether `frequency` (Hz) lies in `[min_frequency, max_frequency)`,
+    /// or in `(min_frequency, max_frequency]` for an ITU band.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfconversions::band::{band_by_name, Scheme};
+    /// let x = band_by_name(Scheme::Ieee, "X").unwrap();
+    /// assert!(x.contains(10e9));
+    /// assert!(!x.contains(12e9));
+    /// let uhf = band_by_name(Scheme::Itu, "UHF").unwrap();
+    /// assert!(uhf.contains(3e9));
+    /// assert!(!uhf.contains(300e6));
+    /// ```
+    #[must_use]
+    pub fn contains(&self, frequency: f64) -> bool {
+        if self.upper_edge_inclusive {
+            frequency > self.min_frequency && frequency <= self.max_frequency
+        } else {
+            frequency >= self.min_frequency && frequency < self.max_frequency
+        }
+    }
+}
+
+/// Band designations of one frequency across all schemes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Labels {
+    /// IEEE radar letter band.
+    pub ieee: Option<&'static Band>,
+    /// ITU band number (1–12).
+    pub itu_number: Option<u32>,
+    /// ITU band.
+    pub itu: Option<&'static Band>,
+    /// NATO letter band.
+    pub nato: Option<&'static Band>,
+    /// Satellite band.
+    pub satellite: Option<&'static Band>,
+}
+
+const fn band(name: &'static str, min_frequency: f64, max_frequency: f64) -> Band {
+    Band {
+        name,
+        min_frequency,
+        max_frequency,
+        upper_edge_inclusive: false,
+    }
+}
+
+/// ITU band, which excludes its lower and includes its upper limit.
+const fn itu(name: &'static str, min_frequency: f64, max_frequency: f64) -> Band {
+    Band {
+        name,
+        min_frequency,
+        max_frequency,
+        upper_edge_inclusive: true,
+    }
+}
+
+/// IEEE Std 521 letter bands.
+pub const IEEE_BANDS: [Band; 13] = [
+    band("HF", 3e6, 30e6),
+    band("VHF", 30e6, 300e6),
+    band("UHF", 300e6, 1e9),
+    band("L", 1e9, 2e9),
+    band("S", 2e9, 4e9),
+    band("C", 4e9, 8e9),
+    band("X", 8e9, 12e9),
+    band("Ku", 12e9, 18e9),
+    band("K", 18e9, 27e9),
+    band("Ka", 27e9, 40e9),
+    band("V", 40e9, 75e9),
+    band("W", 75e9, 110e9),
+    band("mm", 110e9, 300e9),
+];
+
+/// ITU bands 1–12; band N spans 0.3·10ᴺ < f ≤ 3·10ᴺ Hz (RR No. 2.1).
+pub const ITU_BANDS: [Band; 12] = [
+    itu("ELF", 3.0, 30.0),
+    itu("SLF", 30.0, 300.0),
+    itu("ULF", 300.0, 3e3),
+    itu("VLF", 3e3, 30e3),
+    itu("LF", 30e3, 300e3),
+    itu("MF", 300e3, 3e6),
+    itu("HF", 3e6, 30e6),
+    itu("VHF", 30e6, 300e6),
+    itu("UHF", 300e6, 3e9),
+    itu("SHF", 3e9, 30e9),
+    itu("EHF", 30e9, 300e9),
+    itu("THF", 300e9, 3e12),
+];
+
+/// NATO / EU electronic-warfare letter bands.
+pub const NATO_BANDS: [Band; 13] = [
+    band("A", 0.0, 250e6),
+    band("B", 250e6, 500e6),
+    band("C", 500e6, 1e9),
+    band("D", 1e9, 2e9),
+    band("E", 2e9, 3e9),
+    band("F", 3e9, 4e9),
+    band("G", 4e9, 6e9),
+    band("H", 6e9, 8e9),
+    band("I", 8e9, 10e9),
+    band("J", 10e9, 20e9),
+    band("K", 20e9, 40e9),
+    band("L", 40e9, 60e9),
+    band("M", 60e9, 100e9),
+];
+
+/// Satellite-communications band names, made contiguous where sources
+/// overlap (Q and V).
+pub const SATELLITE_BANDS: [Band; 10] = [
+    band("L", 1e9, 2e9),
+    band("S", 2e9, 4e9),
+    band("C", 4e9, 8e9),
+    band("X", 8e9, 12e9),
+    band("Ku", 12e9, 18e9),
+    band("K", 18e9, 26.5e9),
+    band("Ka", 26.5e9, 40e9),
+    band("Q", 40e9, 50e9),
+    band("V", 50e9, 75e9),
+    band("W", 75e9, 110e9),
+];
+
+/// Band containing `frequency` (Hz) in a scheme, or `None` outside the
+/// scheme's coverage.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::band::{band_for_frequency, Scheme};
+/// assert_eq!(band_for_frequency(Scheme::Ieee, 14.25e9).unwrap().name, "Ku");
+/// assert_eq!(band_for_frequency(Scheme::Itu, 14.25e9).unwrap().name, "SHF");
+/// assert_eq!(band_for_frequency(Scheme::Nato, 14.25e9).unwrap().name, "J");
+/// assert!(band_for_frequency(Scheme::Satellite, 500e6).is_none());
+/// ```
+#[must_use]
+pub fn band_for_frequency(scheme: Scheme, frequency: f64) -> Option<&'static Band> {
+    scheme.bands().iter().find(|band| band.contains(frequency))
+}
+
+/// Look up a band by name within a scheme. Matching ignores case, so
+/// `"ku"` finds `"Ku"`; note that `"K"` and `"Ka"` remain distinct.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::band::{band_by_name, Scheme};
+/// let ka = band_by_name(Scheme::Ieee, "ka").unwrap();
+/// assert_eq!((ka.min_frequency, ka.max_frequency), (27e9, 40e9));
+/// assert!(band_by_name(Scheme::Nato, "Z").is_none());
+/// ```
+#[must_use]
+pub fn band_by_name(scheme: Scheme, name: &str) -> Option<&'static Band> {
+    scheme
+        .bands()
+        .iter()
+        .find(|band| band.name.eq_ignore_ascii_case(name))
+}
+
+/// ITU band number N (1–12) for a frequency (Hz), where band N spans
+/// 0.3·10ᴺ < f ≤ 3·10ᴺ.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::band::itu_band_number;
+/// assert_eq!(itu_band_number(2.4e9), Some(9));
+/// assert_eq!(itu_band_number(10e9), Some(10));
+/// assert_eq!(itu_band_number(3e9), Some(9));
+/// assert_eq!(itu_band_number(1.0), None);
+/// ```
+#[must_use]
+pub fn itu_band_number(frequency: f64) -> Option<u32> {
+    ITU_BANDS
+        .iter()
+        .position(|band| band.contains(frequency))
+        .map(|i| i as u32 + 1)
+}
+
+/// ITU band by number (1–12).
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::band::itu_band;
+/// assert_eq!(itu_band(8).unwrap().name, "VHF");
+/// assert!(itu_band(13).is_none());
+/// ```
+#[must_use]
+pub fn itu_band(number: u32) -> Option<&'static Band> {
+    number
+        .checked_sub(1)
+        .and_then(|i| ITU_BANDS.get(i as usize))
+}
+
+/// All designations of a frequency (Hz), e.g. for labelling reports.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::band::labels;
+/// let l = labels(9.4e9);
+/// assert_eq!(l.ieee.unwrap().name, "X");
+/// assert_eq!((l.itu_number, l.itu.unwrap().name), (Some(10), "SHF"));
+/// assert_eq!(l.nato.unwrap().name, "I");
+/// assert_eq!(l.satellite.unwrap().name, "X");
+/// ```
+#[must_use]
+pub fn labels(frequency: f64) -> Labels {
+    Labels {
+        ieee: band_for_frequency(Scheme::Ieee, frequency),
+        itu_number: itu_band_number(frequency),
+        itu: band_for_frequency(Scheme::Itu, frequency),
+        nato: band_for_frequency(Scheme::Nato, frequency),
+        satellite: band_for_frequency(Scheme::Satellite, frequency),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Scheme;
+
+    #[test]
+    fn tables_are_contiguous() {
+        for scheme in [Scheme::Ieee, Scheme::Itu, Scheme::Nato, Scheme::Satellite] {
+            for pair in scheme.bands().windows(2) {
+                assert_eq!(pair[0].max_frequency, pair[1].min_frequency);
+            }
+        }
+    }
+
+    #[test]
+    fn itu_bands_follow_decades() {
+        for n in 1..=12 {
+            let band = super::itu_band(n).unwrap();
+            let expected = 3.0 * 10f64.powi(n as i32);
+            assert!((band.max_frequency - expected).abs() < 1e-6 * expected);
+            assert_eq!(super::itu_band_number(band.max_frequency), Some(n));
+        }
+    }
+
+    #[test]
+    fn band_edges_belong_to_upper_band() {
+        assert_eq!(
+            super::band_for_frequency(Scheme::Ieee, 8e9).unwrap().name,
+            "X"
+        );
+        assert_eq!(
+            super::band_for_frequency(Scheme::Ieee, 27e9).unwrap().name,
+            "Ka"
+        );
+    }
+
+    #[test]
+    fn itu_band_edges_belong_to_lower_band() {
+        assert_eq!(super::itu_band_number(300e6), Some(8));
+        assert_eq!(super::itu_band_number(3e9), Some(9));
+        assert_eq!(
+            super::band_for_frequency(Scheme::Itu, 300e6).unwrap().name,
+            "VHF"
+        );
+        assert_eq!(super::itu_band_number(3.0), None);
+        assert_eq!(super::itu_band_number(3e12), Some(12));
+    }
+
+    #[test]
+    fn names_round_trip() {
+        for scheme in [Scheme::Ieee, Scheme::Itu, Scheme::Nato, Scheme::Satellite] {
+            for band in scheme.bands() {
+                assert_eq!(super::band_by_name(scheme, band.name), Some(band));
+            }
+        }
+    }
+}
@@ -0,0 +1,292 @@
+use std::f64::consts::PI;
+
+use crate::filter::{self, Approximation, FilterResponse};
+
+/// FFT window shape, defined by its cosine-sum coefficients.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Window {
+    /// Uniform weighting (no window).
+    Rectangular,
+    /// Hann (raised cosine).
+    Hann,
+    /// Hamming (0.54 / 0.46).
+    Hamming,
+    /// 4-term Blackman-Harris (−92 dB sidelobes).
+    BlackmanHarris,
+    /// 5-term flat-top for amplitude accuracy.
+    FlatTop,
+}
+
+impl Window {
+    /// Cosine-sum coefficients a₀ … aₖ.
+    fn coefficients(self) -> &'static [f64] {
+        match self {
+            Window::Rectangular => &[1.0],
+            Window::Hann => &[0.5, 0.5],
+            Window::Hamming => &[0.54, 0.46],
+            Window::BlackmanHarris => &[0.35875, 0.48829, 0.14128, 0.01168],
+            Window::FlatTop => &[
+                0.215_578_95,
+                0.416_631_58,
+                0.277_263_158,
+                0.083_578_947,
+                0.006_947_368,
+            ],
+        }
+    }
+}
+
+/// Noise bandwidth of a Butterworth lowpass, or of the equivalent bandpass
+/// when `bandwidth_3db` is its full 3 dB width.
+///
+/// ENBW = B₃·(π / 2n) / sin(π / 2n)
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::bandwidth::butterworth_noise_bandwidth;
+/// // Single pole: π/2 × the 3 dB bandwidth
+/// let enbw = butterworth_noise_bandwidth(1e6, 1);
+/// assert!((enbw - 1.5708e6).abs() < 100.0);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `order` is zero.
+#[doc(alias = "ENBW")]
+#[must_use]
+pub fn butterworth_noise_bandwidth(bandwidth_3db: f64, order: u32) -> f64 {
+    assert!(order >= 1, "order must be at least 1");
+    let x = PI / (2 * order) as f64;
+    bandwidth_3db * x / x.sin()
+}
+
+/// Noise bandwidth of a Chebyshev lowpass with the given ripple-edge
+/// bandwidth, or of the equivalent bandpass.
+///
+/// Integrated numerically from the ladder prototype, referenced to the peak
+/// passband gain.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::bandwidth::chebyshev_noise_bandwidth;
+/// let enbw = chebyshev_noise_bandwidth(1e6, 5, 0.5);
+/// assert!(enbw > 0.9e6 && enbw < 1.1e6);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `order` is zero or `ripple_db` is not positive.
+#[doc(alias = "ENBW")]
+#[must_use]
+pub fn chebyshev_noise_bandwidth(ripple_bandwidth: f64, order: u32, ripple_db: f64) -> f64 {
+    let prototype = filter::prototype(Approximation::Chebyshev { ripple_db }, order as usize)
+        .expect("Chebyshev prototypes are always realizable");
+    filter::noise_bandwidth(
+        &prototype,
+        FilterResponse::Lowpass {
+            cutoff: ripple_bandwidth,
+        },
+    )
+}
+
+/// Noise bandwidth of a Gaussian filter from its full 3 dB bandwidth.
+///
+/// ENBW = B₃·√(π / (4·ln 2)) ≈ 1.0645·B₃
+///
+/// This also describes the digital Gaussian RBW filters of modern spectrum
+/// analyzers.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::bandwidth::gaussian_noise_bandwidth;
+/// let enbw = gaussian_noise_bandwidth(1e3);
+/// assert!((enbw - 1064.5).abs() < 0.1);
+/// ```
+#[doc(alias = "ENBW")]
+#[must_use]
+pub fn gaussian_noise_bandwidth(bandwidth_3db: f64) -> f64 {
+    bandwidth_3db * (PI / (4.0 * 2.0_f64.ln())).sqrt()
+}
+
+/// Noise bandwidth of a raised-cosine filter.
+///
+/// ENBW = Rₛ·(1 − α/4)
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::bandwidth::raised_cosine_noise_bandwidth;
+/// let enbw = raised_cosine_noise_bandwidth(1e6, 0.35);
+/// assert!((enbw - 912_500.0).abs() < 1e-6);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `roll_off` is outside 0–1.
+#[doc(alias = "ENBW")]
+#[must_use]
+pub fn raised_cosine_noise_bandwidth(symbol_rate: f64, roll_off: f64) -> f64 {
+    assert!(
+        (0.0..=1.0).contains(&roll_off),
+        "roll-off must be between 0 and 1"
+    );
+    symbol_rate * (1.0 - roll_off / 4.0)
+}
+
+/// Noise bandwidth of a root-raised-cosine (matched) filter.
+///
+/// ENBW = Rₛ for any roll-off, since |H_RRC|² is a raised cosine with unit
+/// area per symbol. The roll-off is accepted only to mirror
+/// [`raised_cosine_noise_bandwidth`] and does not affect the result.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::bandwidth::root_raised_cosine_noise_bandwidth;
+/// assert_eq!(root_raised_cosine_noise_bandwidth(1e6, 0.22), 1e6);
+/// ```
+#[doc(alias = "ENBW")]
+#[doc(alias = "RRC")]
+#[must_use]
+pub fn root_raised_cosine_noise_bandwidth(symbol_rate: f64, _roll_off: f64) -> f64 {
+    symbol_rate
+}
+
+/// Noise bandwidth of an analog spectrum-analyzer RBW filter built from
+/// `poles` synchronously tuned single-pole stages.
+///
+/// ENBW / RBW = [π·C(2n − 2, n − 1) / (2·4ⁿ⁻¹)] / √(2^(1/n) − 1)
+///
+/// giving 1.128 for the common 4-pole filter and 1.114 for 5 poles.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::bandwidth::synchronously_tuned_noise_bandwidth;
+/// let enbw = synchronously_tuned_noise_bandwidth(10e3, 4);
+/// assert!((enbw - 11.28e3).abs() < 10.0);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `poles` is zero.
+#[doc(alias = "ENBW")]
+#[doc(alias = "RBW")]
+#[must_use]
+pub fn synchronously_tuned_noise_bandwidth(rbw: f64, poles: u32) -> f64 {
+    assert!(poles >= 1, "poles must be at least 1");
+    let n = poles as f64;
+    // C(2n − 2, n − 1) / 4ⁿ⁻¹, built up term by term to avoid overflow.
+    let central = (1..poles).fold(1.0, |acc, k| acc * (2 * k - 1) as f64 / (2 * k) as f64);
+    let area = PI * central / 2.0;
+    let half_width = (2.0_f64.powf(1.0 / n) - 1.0).sqrt();
+    rbw * area / half_width
+}
+
+/// Noise bandwidth of an FFT window in bins.
+///
+/// ENBW = N·Σw² / (Σw)² = (a₀² + ½·Σaₖ²) / a₀² for a cosine-sum window
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::bandwidth::{window_noise_bandwidth_bins, Window};
+/// assert!((window_noise_bandwidth_bins(Window::Hann) - 1.5).abs() < 1e-12);
+/// ```
+#[doc(alias = "ENBW")]
+#[must_use]
+pub fn window_noise_bandwidth_bins(window: Window) -> f64 {
+    let a = window.coefficients();
+    let a0 = a[0];
+    let harmonics: f64 = a[1..].iter().map(|c| c * c).sum();
+    (a0 * a0 + harmonics / 2.0) / (a0 * a0)
+}
+
+/// Noise bandwidth in Hz of an FFT bin for a window, sample rate and FFT
+/// length.
+///
+/// ENBW = bins·f_s / N
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::bandwidth::{window_noise_bandwidth, Window};
+/// let enbw = window_noise_bandwidth(Window::Hann, 1.024e6, 1024);
+/// assert!((enbw - 1500.0).abs() < 1e-9);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `fft_size` is zero.
+#[doc(alias = "ENBW")]
+#[doc(alias = "bin width")]
+#[must_use]
+pub fn window_noise_bandwidth(window: Window, sample_rate: f64, fft_size: usize) -> f64 {
+    assert!(fft_size > 0, "FFT size must be positive");
+    window_noise_bandwidth_bins(window) * sample_rate / fft_size as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Window;
+
+    #[test]
+    fn butterworth_approaches_brick_wall() {
+        let enbw = super::butterworth_noise_bandwidth(1.0, 10);
+        assert!((enbw - 1.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn chebyshev_approaches_ripple_edge_at_high_order() {
+        let enbw = super::chebyshev_noise_bandwidth(1.0, 9, 0.1);
+        assert!(enbw > 1.0 && enbw < 1.1);
+    }
+
+    #[test]
+    fn raised_cosine_limits() {
+        assert_eq!(super::raised_cosine_noise_bandwidth(1.0, 0.0), 1.0);
+        assert_eq!(super::raised_cosine_noise_bandwidth(1.0, 1.0), 0.75);
+    }
+
+    #[test]
+    fn synchronously_tuned_values() {
+        // One pole is the single-tuned π/2 case.
+        let one = super::synchronously_tuned_noise_bandwidth(1.0, 1);
+        assert!((one - std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+        let five = super::synchronously_tuned_noise_bandwidth(1.0, 5);
+        assert!((five - 1.114).abs() < 1e-3);
+    }
+
+    #[test]
+    fn window_bins() {
+        let cases = [
+            (Window::Rectangular, 1.0),
+            (Window::Hamming, 1.3628),
+            (Window::BlackmanHarris, 2.0044),
+            (Window::FlatTop, 3.7702),
+        ];
+        for (window, expected) in cases {
+            let bins = super::window_noise_bandwidth_bins(window);
+            assert!((bins - expected).abs() < 1e-3, "{window:?}: {bins}");
+        }
+    }
+
+    #[test]
+    fn root_raised_cosine_ignores_roll_off() {
+        for roll_off in [0.0, 0.22, 0.35, 0.5, 1.0] {
+            assert_eq!(
+                super::root_raised_cosine_noise_bandwidth(2e6, roll_off),
+                2e6
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "roll-off must be between 0 and 1")]
+    fn raised_cosine_rejects_bad_roll_off() {
+        let _ = super::raised_cosine_noise_bandwidth(1.0, 1.5);
+    }
+}
@@ -0,0 +1,247 @@
+use crate::ber::{self, Modulation};
+use crate::noise::noise_power_from_bandwidth;
+use crate::power::{db_to_linear, dbm_to_watts, linear_to_db};
+
+/// Shannon–Hartley channel capacity in bits per second.
+///
+/// C = B·log₂(1 + SNR)
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::capacity::shannon_capacity;
+/// // 1 MHz at 30 dB SNR → ~9.97 Mb/s
+/// let c = shannon_capacity(1e6, 30.0);
+/// assert!((c - 9.967e6).abs() < 1e3);
+/// ```
+#[doc(alias = "Shannon-Hartley")]
+#[must_use]
+pub fn shannon_capacity(bandwidth: f64, snr_db: f64) -> f64 {
+    bandwidth * spectral_efficiency(snr_db)
+}
+
+/// Channel capacity (b/s) from received power (dBm) against thermal noise
+/// kTB at a system noise temperature.
+///
+/// C = B·log₂(1 + P / kTB)
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::capacity::shannon_capacity_from_power;
+/// // −100 dBm in 1 MHz at 290 K: SNR ≈ 14 dB
+/// let c = shannon_capacity_from_power(1e6, -100.0, 290.0);
+/// assert!((c - 4.70e6).abs() < 0.01e6);
+/// ```
+#[must_use]
+pub fn shannon_capacity_from_power(bandwidth: f64, power_dbm: f64, noise_temperature: f64) -> f64 {
+    let snr = dbm_to_watts(power_dbm) / noise_power_from_bandwidth(noise_temperature, bandwidth);
+    bandwidth * (1.0 + snr).log2()
+}
+
+/// Maximum spectral efficiency in b/s/Hz at an SNR (dB).
+///
+/// η = log₂(1 + SNR)
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::capacity::spectral_efficiency;
+/// assert!((spectral_efficiency(0.0) - 1.0).abs() < 1e-12);
+/// ```
+#[must_use]
+pub fn spectral_efficiency(snr_db: f64) -> f64 {
+    (1.0 + db_to_linear(snr_db)).log2()
+}
+
+/// Minimum SNR (dB) that supports a spectral efficiency (b/s/Hz).
+///
+/// SNR = 2^η − 1
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::capacity::minimum_snr_db;
+/// assert!((minimum_snr_db(1.0) - 0.0).abs() < 1e-12);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `spectral_efficiency` is not positive.
+#[must_use]
+pub fn minimum_snr_db(spectral_efficiency: f64) -> f64 {
+    assert!(
+        spectral_efficiency > 0.0,
+        "spectral efficiency must be positive"
+    );
+    linear_to_db(spectral_efficiency.exp2() - 1.0)
+}
+
+/// Minimum Eb/N₀ (dB) that supports a spectral efficiency (b/s/Hz).
+///
+/// Eb/N₀ = (2^η − 1) / η, approaching ln 2 (−1.59 dB) as η → 0
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::capacity::minimum_ebn0_db;
+/// // The ultimate Shannon limit
+/// assert!((minimum_ebn0_db(0.0) - (-1.5917)).abs() < 1e-4);
+/// // 2 b/s/Hz needs at least 1.76 dB
+/// assert!((minimum_ebn0_db(2.0) - 1.7609).abs() < 1e-4);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `spectral_efficiency` is negative.
+#[doc(alias = "Shannon limit")]
+#[must_use]
+pub fn minimum_ebn0_db(spectral_efficiency: f64) -> f64 {
+    assert!(
+        spectral_efficiency >= 0.0,
+        "spectral efficiency must not be negative"
+    );
+    if spectral_efficiency == 0.0 {
+        return linear_to_db(std::f64::consts::LN_2);
+    }
+    // expm1 keeps precision for small η.
+    linear_to_db((spectral_efficiency * std::f64::consts::LN_2).exp_m1() / spectral_efficiency)
+}
+
+/// Spectral efficiency (b/s/Hz) of a modulation and coding operating point
+/// in its occupied bandwidth Rₛ·(1 + α).
+///
+/// η = k·r / (1 + α)
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::capacity::operating_spectral_efficiency;
+/// // 8-PSK rate 3/4, α = 0.2 → 1.875 b/s/Hz
+/// assert!((operating_spectral_efficiency(3, 0.75, 0.2) - 1.875).abs() < 1e-12);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `code_rate` is outside (0, 1] or `roll_off` outside 0–1.
+#[must_use]
+pub fn operating_spectral_efficiency(bits_per_symbol: u32, code_rate: f64, roll_off: f64) -> f64 {
+    assert!(
+        code_rate > 0.0 && code_rate <= 1.0,
+        "code rate must be in (0, 1]"
+    );
+    assert!(
+        (0.0..=1.0).contains(&roll_off),
+        "roll-off must be between 0 and 1"
+    );
+    bits_per_symbol as f64 * code_rate / (1.0 + roll_off)
+}
+
+/// Gap (dB) between an operating point and the Shannon limit at the same
+/// spectral efficiency.
+///
+/// gap = Eb/N₀ − 10·log₁₀((2^η − 1)/η)
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::capacity::shannon_gap_db;
+/// // A code needing 2.5 dB at 1 b/s/Hz is 2.5 dB from the 0 dB bound
+/// assert!((shannon_gap_db(2.5, 1.0) - 2.5).abs() < 1e-12);
+/// ```
+#[doc(alias = "Shannon gap")]
+#[must_use]
+pub fn shannon_gap_db(ebn0_db: f64, spectral_efficiency: f64) -> f64 {
+    ebn0_db - minimum_ebn0_db(spectral_efficiency)
+}
+
+/// Shannon gap (dB) of an uncoded modulation at a target BER, with Nyquist
+/// signalling (η = log₂M). Orthogonal M-FSK occupies about M·Rs, so its
+/// efficiency is η = log₂M / M.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::ber::Modulation;
+/// use rfconversions::capacity::uncoded_shannon_gap_db;
+/// // Uncoded BPSK at 1e-5 sits ~9.6 dB above the 1 b/s/Hz bound
+/// let gap = uncoded_shannon_gap_db(Modulation::Bpsk, 1e-5);
+/// assert!((gap - 9.59).abs() < 0.02);
+/// ```
+///
+/// # Panics
+///
+/// Panics under the same conditions as [`crate::ber::required_ebn0_db`].
+#[must_use]
+pub fn uncoded_shannon_gap_db(modulation: Modulation, target_ber: f64) -> f64 {
+    let ebn0 = ber::required_ebn0_db(modulation, target_ber);
+    shannon_gap_db(ebn0, uncoded_spectral_efficiency(modulation))
+}
+
+/// Spectral efficiency (b/s/Hz) of an uncoded modulation with Nyquist
+/// signalling.
+fn uncoded_spectral_efficiency(modulation: Modulation) -> f64 {
+    let bits = modulation.bits_per_symbol() as f64;
+    match modulation {
+        Modulation::Fsk(m) => bits / m as f64,
+        _ => bits,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ber::Modulation;
+
+    #[test]
+    fn minimum_snr_consistent_with_capacity() {
+        let snr = super::minimum_snr_db(4.0);
+        assert!((super::spectral_efficiency(snr) - 4.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn minimum_ebn0_is_continuous_near_zero() {
+        let limit = super::minimum_ebn0_db(0.0);
+        let tiny = super::minimum_ebn0_db(1e-9);
+        assert!((limit - tiny).abs() < 1e-6);
+    }
+
+    #[test]
+    fn ebn0_bound_matches_snr_bound() {
+        // Eb/N₀ = SNR / η at the bound
+        let eta = 3.0_f64;
+        let expected = super::minimum_snr_db(eta) - 10.0 * eta.log10();
+        assert!((super::minimum_ebn0_db(eta) - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn capacity_from_power_matches_snr_form() {
+        let n = crate::power::watts_to_dbm(crate::noise::noise_power_from_bandwidth(290.0, 1e6));
+        let a = super::shannon_capacity_from_power(1e6, n + 10.0, 290.0);
+        let b = super::shannon_capacity(1e6, 10.0);
+        assert!((a - b).abs() < 1e-6);
+    }
+
+    #[test]
+    fn uncoded_qam_gap_is_roughly_constant() {
+        // Uncoded QAM sits about 8–9 dB from capacity at 1e-6 regardless of order.
+        for m in [4, 16, 64, 256] {
+            let gap = super::uncoded_shannon_gap_db(Modulation::Qam(m), 1e-6);
+            assert!(gap > 7.5 && gap < 9.5, "{m}-QAM: {gap}");
+        }
+    }
+
+    #[test]
+    fn uncoded_fsk_gap_uses_fsk_bandwidth() {
+        // 16-FSK carries 4 bits in ~16·Rs: η = 0.25 b/s/Hz, not 4
+        let ebn0 = crate::ber::required_ebn0_db(Modulation::Fsk(16), 1e-5);
+        let gap = super::uncoded_shannon_gap_db(Modulation::Fsk(16), 1e-5);
+        assert!((gap - (ebn0 - super::minimum_ebn0_db(0.25))).abs() < 1e-12);
+        assert!((super::minimum_ebn0_db(0.25) - (-1.21)).abs() < 0.01);
+    }
+
+    #[test]
+    #[should_panic(expected = "spectral efficiency must not be negative")]
+    fn rejects_negative_efficiency() {
+        let _ = super::minimum_ebn0_db(-1.0);
+    }
+}
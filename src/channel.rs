@@ -0,0 +1,608 @@
+/// Link direction of an FDD carrier.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Link {
+    /// Base station to user equipment.
+    Downlink,
+    /// User equipment to base station.
+    Uplink,
+}
+
+/// An LTE operating band from 3GPP TS 36.101. TDD bands have identical
+/// downlink and uplink entries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LteBand {
+    /// E-UTRA band number.
+    pub number: u32,
+    /// Downlink lower edge F_DL_low in kHz.
+    pub dl_low_khz: u32,
+    /// First downlink EARFCN, N_Offs-DL.
+    pub dl_offset: u32,
+    /// Last downlink EARFCN.
+    pub dl_last: u32,
+    /// Uplink lower edge F_UL_low in kHz.
+    pub ul_low_khz: u32,
+    /// First uplink EARFCN, N_Offs-UL.
+    pub ul_offset: u32,
+    /// Last uplink EARFCN.
+    pub ul_last: u32,
+}
+
+impl LteBand {
+    /// `(first, last)` EARFCN of a link in this band.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfconversions::channel::{lte_band, Link};
+    /// assert_eq!(lte_band(3).unwrap().earfcn_range(Link::Uplink), (19200, 19949));
+    /// ```
+    #[must_use]
+    pub fn earfcn_range(&self, link: Link) -> (u32, u32) {
+        match link {
+            Link::Downlink => (self.dl_offset, self.dl_last),
+            Link::Uplink => (self.ul_offset, self.ul_last),
+        }
+    }
+
+    fn low_khz(&self, link: Link) -> u32 {
+        match link {
+            Link::Downlink => self.dl_low_khz,
+            Link::Uplink => self.ul_low_khz,
+        }
+    }
+}
+
+const fn lte(number: u32, dl: (u32, u32, u32), ul: (u32, u32, u32)) -> LteBand {
+    LteBand {
+        number,
+        dl_low_khz: dl.0,
+        dl_offset: dl.1,
+        dl_last: dl.2,
+        ul_low_khz: ul.0,
+        ul_offset: ul.1,
+        ul_last: ul.2,
+    }
+}
+
+/// Commonly deployed LTE bands, ordered by band number.
+pub const LTE_BANDS: [LteBand; 20] = [
+    lte(1, (2_110_000, 0, 599), (1_920_000, 18000, 18599)),
+    lte(2, (1_930_000, 600, 1199), (1_850_000, 18600, 19199)),
+    lte(3, (1_805_000, 1200, 1949), (1_710_000, 19200, 19949)),
+    lte(4, (2_110_000, 1950, 2399), (1_710_000, 19950, 20399)),
+    lte(5, (869_000, 2400, 2649), (824_000, 20400, 20649)),
+    lte(7, (2_620_000, 2750, 3449), (2_500_000, 20750, 21449)),
+    lte(8, (925_000, 3450, 3799), (880_000, 21450, 21799)),
+    lte(12, (729_000, 5010, 5179), (699_000, 23010, 23179)),
+    lte(13, (746_000, 5180, 5279), (777_000, 23180, 23279)),
+    lte(14, (758_000, 5280, 5379), (788_000, 23280, 23379)),
+    lte(17, (734_000, 5730, 5849), (704_000, 23730, 23849)),
+    lte(20, (791_000, 6150, 6449), (832_000, 24150, 24449)),
+    lte(25, (1_930_000, 8040, 8689), (1_850_000, 26040, 26689)),
+    lte(26, (859_000, 8690, 9039), (814_000, 26690, 27039)),
+    lte(28, (758_000, 9210, 9659), (703_000, 27210, 27659)),
+    lte(38, (2_570_000, 37750, 38249), (2_570_000, 37750, 38249)),
+    lte(40, (2_300_000, 38650, 39649), (2_300_000, 38650, 39649)),
+    lte(41, (2_496_000, 39650, 41589), (2_496_000, 39650, 41589)),
+    lte(66, (2_110_000, 66436, 67335), (1_710_000, 131_972, 132_671)),
+    lte(71, (617_000, 68586, 68935), (663_000, 133_122, 133_471)),
+];
+
+/// EARFCN raster spacing in kHz.
+const EARFCN_STEP_KHZ: u32 = 100;
+
+/// Highest NR-ARFCN (100 GHz).
+const NR_ARFCN_MAX: u32 = 3_279_165;
+
+/// NR global frequency raster from TS 38.104 §5.4.2.1:
+/// (first N_REF, ΔF_Global in Hz, F_REF-Offs in Hz).
+const NR_RASTER: [(u32, f64, f64); 3] = [
+    (0, 5e3, 0.0),
+    (600_000, 15e3, 3e9),
+    (2_016_667, 60e3, 24_250.08e6),
+];
+
+/// Highest GSCN (100 GHz).
+const GSCN_MAX: u32 = 26_639;
+
+/// Look up an LTE band by number.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::channel::lte_band;
+/// assert_eq!(lte_band(7).unwrap().dl_low_khz, 2_620_000);
+/// assert!(lte_band(6).is_none());
+/// ```
+#[must_use]
+pub fn lte_band(number: u32) -> Option<&'static LteBand> {
+    LTE_BANDS.iter().find(|band| band.number == number)
+}
+
+/// Band and link an EARFCN belongs to.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::channel::{earfcn_band, Link};
+/// let (band, link) = earfcn_band(18300).unwrap();
+/// assert_eq!((band.number, link), (1, Link::Uplink));
+/// ```
+#[must_use]
+pub fn earfcn_band(earfcn: u32) -> Option<(&'static LteBand, Link)> {
+    LTE_BANDS.iter().find_map(|band| {
+        [Link::Downlink, Link::Uplink].into_iter().find_map(|link| {
+            let (first, last) = band.earfcn_range(link);
+            (first..=last).contains(&earfcn).then_some((band, link))
+        })
+    })
+}
+
+/// Carrier frequency (Hz) of an LTE EARFCN.
+///
+/// F = F_low + 0.1 MHz·(N − N_Offs)
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::channel::earfcn_to_frequency;
+/// // Band 3 downlink
+/// assert_eq!(earfcn_to_frequency(1575), Some(1842.5e6));
+/// // Band 1 uplink
+/// assert_eq!(earfcn_to_frequency(18300), Some(1950e6));
+/// assert_eq!(earfcn_to_frequency(70000), None);
+/// ```
+#[doc(alias = "EARFCN")]
+#[must_use]
+pub fn earfcn_to_frequency(earfcn: u32) -> Option<f64> {
+    earfcn_band(earfcn).map(|(band, link)| {
+        let (first, _) = band.earfcn_range(link);
+        f64::from(band.low_khz(link) + EARFCN_STEP_KHZ * (earfcn - first)) * 1e3
+    })
+}
+
+/// EARFCN of a carrier frequency (Hz) in a band and link, or `None` if the
+/// frequency is not finite, outside the band or off the 100 kHz raster.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::channel::{frequency_to_earfcn, Link};
+/// assert_eq!(frequency_to_earfcn(3, Link::Downlink, 1842.5e6), Some(1575));
+/// assert_eq!(frequency_to_earfcn(3, Link::Downlink, 1842.55e6), None);
+/// ```
+#[doc(alias = "EARFCN")]
+#[must_use]
+pub fn frequency_to_earfcn(band: u32, link: Link, frequency: f64) -> Option<u32> {
+    if !frequency.is_finite() {
+        return None;
+    }
+    let band = lte_band(band)?;
+    let (first, last) = band.earfcn_range(link);
+    let steps = (frequency / 1e3 - f64::from(band.low_khz(link))) / f64::from(EARFCN_STEP_KHZ);
+    let n = steps.round();
+    if (steps - n).abs() > 1e-6 || n < 0.0 {
+        return None;
+    }
+    // `as` saturates, so a far out-of-band frequency cannot wrap around.
+    let earfcn = first.checked_add(n as u32)?;
+    (earfcn <= last).then_some(earfcn)
+}
+
+/// Frequency (Hz) of a 5G NR-ARFCN on the global raster.
+///
+/// F_REF = F_REF-Offs + ΔF_Global·(N_REF − N_REF-Offs)
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::channel::nr_arfcn_to_frequency;
+/// assert_eq!(nr_arfcn_to_frequency(620000), 3.3e9);
+/// assert_eq!(nr_arfcn_to_frequency(2079167), 28000.08e6);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `arfcn` exceeds 3279165.
+#[doc(alias = "NR-ARFCN")]
+#[must_use]
+pub fn nr_arfcn_to_frequency(arfcn: u32) -> f64 {
+    assert!(arfcn <= NR_ARFCN_MAX, "NR-ARFCN must not exceed 3279165");
+    let &(offset, step, base) = NR_RASTER
+        .iter()
+        .rev()
+        .find(|&&(first, _, _)| arfcn >= first)
+        .expect("raster starts at zero");
+    base + step * f64::from(arfcn - offset)
+}
+
+/// Nearest NR-ARFCN to a frequency (Hz).
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::channel::frequency_to_nr_arfcn;
+/// assert_eq!(frequency_to_nr_arfcn(3.3e9), 620000);
+/// assert_eq!(frequency_to_nr_arfcn(28000.08e6), 2079167);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `frequency` is outside 0–100 GHz.
+#[doc(alias = "NR-ARFCN")]
+#[must_use]
+pub fn frequency_to_nr_arfcn(frequency: f64) -> u32 {
+    assert!(
+        (0.0..=100e9).contains(&frequency),
+        "frequency must be within 0–100 GHz"
+    );
+    let i = NR_RASTER
+        .iter()
+        .rposition(|&(_, _, base)| frequency >= base)
+        .expect("raster starts at zero");
+    let (offset, step, base) = NR_RASTER[i];
+    let arfcn = offset + ((frequency - base) / step).round() as u32;
+    // Stay within this raster's ARFCN range just below the next raster.
+    NR_RASTER
+        .get(i + 1)
+        .map_or(arfcn.min(NR_ARFCN_MAX), |&(next, _, _)| arfcn.min(next - 1))
+}
+
+/// SS block frequency (Hz) of a 5G NR GSCN on the synchronization raster.
+///
+/// Below 3 GHz: N·1.2 MHz + M·50 kHz with GSCN = 3N + (M − 3)/2;
+/// 3–24.25 GHz: 3 GHz + N·1.44 MHz; above: 24250.08 MHz + N·17.28 MHz
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::channel::gscn_to_frequency;
+/// assert_eq!(gscn_to_frequency(5279), 2112.05e6);
+/// assert!((gscn_to_frequency(7711) - 3305.28e6).abs() < 1e-3);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `gscn` is outside 2–26639.
+#[doc(alias = "GSCN")]
+#[must_use]
+pub fn gscn_to_frequency(gscn: u32) -> f64 {
+    assert!(
+        (2..=GSCN_MAX).contains(&gscn),
+        "GSCN must be within 2–26639"
+    );
+    if gscn < 7499 {
+        let n = (gscn + 1) / 3;
+        let m = 2 * (gscn as i64 - 3 * n as i64) + 3;
+        f64::from(n) * 1.2e6 + m as f64 * 50e3
+    } else if gscn < 22256 {
+        3e9 + f64::from(gscn - 7499) * 1.44e6
+    } else {
+        24_250.08e6 + f64::from(gscn - 22256) * 17.28e6
+    }
+}
+
+/// GSCN of the synchronization raster point nearest a frequency (Hz).
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::channel::{frequency_to_gscn, gscn_to_frequency};
+/// assert_eq!(frequency_to_gscn(2112.0e6), 5279);
+/// assert_eq!(frequency_to_gscn(gscn_to_frequency(7711)), 7711);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `frequency` is outside 0–100 GHz.
+#[doc(alias = "GSCN")]
+#[must_use]
+pub fn frequency_to_gscn(frequency: f64) -> u32 {
+    assert!(
+        (0.0..=100e9).contains(&frequency),
+        "frequency must be within 0–100 GHz"
+    );
+    let estimate = if frequency < 3e9 {
+        (frequency / 0.4e6) as u32
+    } else if frequency < 24_250.08e6 {
+        7499 + ((frequency - 3e9) / 1.44e6) as u32
+    } else {
+        22256 + ((frequency - 24_250.08e6) / 17.28e6) as u32
+    };
+    (estimate.saturating_sub(2).max(2)..=(estimate + 2).min(GSCN_MAX))
+        .min_by(|&a, &b| {
+            (gscn_to_frequency(a) - frequency)
+                .abs()
+                .total_cmp(&(gscn_to_frequency(b) - frequency).abs())
+        })
+        .expect("search window is not empty")
+}
+
+/// Wi-Fi frequency band.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WifiBand {
+    /// 2.4 GHz ISM band, channels 1–14.
+    TwoPointFourGhz,
+    /// 5 GHz U-NII bands, channels 36–177.
+    FiveGhz,
+    /// 6 GHz band, channels 1–233.
+    SixGhz,
+}
+
+impl WifiBand {
+    /// Channel starting frequency in Hz; channel n is centered at
+    /// start + n·5 MHz (except 2.4 GHz channel 14 and 6 GHz channel 2).
+    fn start(&self) -> f64 {
+        match self {
+            WifiBand::TwoPointFourGhz => 2407e6,
+            WifiBand::FiveGhz => 5000e6,
+            WifiBand::SixGhz => 5950e6,
+        }
+    }
+
+    /// Whether `channel` is a valid 20 MHz channel number.
+    // `is_multiple_of` needs Rust 1.87; keep `%` for older toolchains.
+    #[allow(clippy::manual_is_multiple_of)]
+    fn is_20mhz_channel(&self, channel: u32) -> bool {
+        match self {
+            WifiBand::TwoPointFourGhz => (1..=14).contains(&channel),
+            WifiBand::FiveGhz => {
+                ((36..=64).contains(&channel) || (100..=144).contains(&channel)) && channel % 4 == 0
+                    || (149..=177).contains(&channel) && channel % 4 == 1
+            }
+            WifiBand::SixGhz => (1..=233).contains(&channel) && channel % 4 == 1,
+        }
+    }
+}
+
+/// Center frequency (Hz) of a Wi-Fi channel number. Bonded 40/80/160 MHz
+/// channels are addressed by their center channel number, e.g. 42 for the
+/// 80 MHz channel spanning 36–48.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::channel::{wifi_channel_to_frequency, WifiBand};
+/// assert_eq!(wifi_channel_to_frequency(WifiBand::TwoPointFourGhz, 6), Some(2437e6));
+/// assert_eq!(wifi_channel_to_frequency(WifiBand::TwoPointFourGhz, 14), Some(2484e6));
+/// assert_eq!(wifi_channel_to_frequency(WifiBand::FiveGhz, 42), Some(5210e6));
+/// assert_eq!(wifi_channel_to_frequency(WifiBand::SixGhz, 37), Some(6135e6));
+/// assert_eq!(wifi_channel_to_frequency(WifiBand::FiveGhz, 200), None);
+/// ```
+#[doc(alias = "WLAN")]
+#[must_use]
+pub fn wifi_channel_to_frequency(band: WifiBand, channel: u32) -> Option<f64> {
+    let valid = match band {
+        WifiBand::TwoPointFourGhz => (1..=14).contains(&channel),
+        WifiBand::FiveGhz => (32..=177).contains(&channel),
+        WifiBand::SixGhz => (1..=233).contains(&channel),
+    };
+    if !valid {
+        return None;
+    }
+    Some(match (band, channel) {
+        (WifiBand::TwoPointFourGhz, 14) => 2484e6,
+        (WifiBand::SixGhz, 2) => 5935e6,
+        _ => band.start() + f64::from(channel) * 5e6,
+    })
+}
+
+/// Band and channel number of a Wi-Fi center frequency (Hz), or `None` if
+/// it is not on a channel center.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::channel::{frequency_to_wifi_channel, WifiBand};
+/// assert_eq!(frequency_to_wifi_channel(5180e6), Some((WifiBand::FiveGhz, 36)));
+/// assert_eq!(frequency_to_wifi_channel(6115e6), Some((WifiBand::SixGhz, 33)));
+/// assert_eq!(frequency_to_wifi_channel(2440e6), None);
+/// ```
+#[doc(alias = "WLAN")]
+#[must_use]
+pub fn frequency_to_wifi_channel(frequency: f64) -> Option<(WifiBand, u32)> {
+    let band = if frequency < 3e9 {
+        WifiBand::TwoPointFourGhz
+    } else if frequency < 5925e6 {
+        WifiBand::FiveGhz
+    } else {
+        WifiBand::SixGhz
+    };
+    let steps = (frequency - band.start()) / 5e6;
+    let candidate = match (band, steps.round()) {
+        (WifiBand::TwoPointFourGhz, _) if frequency == 2484e6 => 14,
+        (WifiBand::SixGhz, _) if frequency == 5935e6 => 2,
+        (_, n) if n >= 1.0 && (steps - n).abs() < 1e-6 => n as u32,
+        _ => return None,
+    };
+    (wifi_channel_to_frequency(band, candidate) == Some(frequency)).then_some((band, candidate))
+}
+
+/// Center channel number of the 20/40/80/160 MHz channel containing a
+/// primary 20 MHz channel, or `None` if the width is unsupported or the
+/// bonded channel would run past the band.
+///
+/// In 2.4 GHz only 20 and 40 MHz exist; 40 MHz bonds upward (HT40+) when
+/// channel + 4 is available in the 13-channel plan, else downward.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::channel::{wifi_center_channel, WifiBand};
+/// assert_eq!(wifi_center_channel(WifiBand::FiveGhz, 44, 80), Some(42));
+/// assert_eq!(wifi_center_channel(WifiBand::FiveGhz, 153, 160), Some(163));
+/// assert_eq!(wifi_center_channel(WifiBand::FiveGhz, 140, 160), None);
+/// assert_eq!(wifi_center_channel(WifiBand::SixGhz, 37, 160), Some(47));
+/// assert_eq!(wifi_center_channel(WifiBand::TwoPointFourGhz, 11, 40), Some(9));
+/// ```
+#[must_use]
+pub fn wifi_center_channel(band: WifiBand, primary: u32, width_mhz: u32) -> Option<u32> {
+    if !band.is_20mhz_channel(primary) || ![20, 40, 80, 160].contains(&width_mhz) {
+        return None;
+    }
+    if width_mhz == 20 {
+        return Some(primary);
+    }
+    if band == WifiBand::TwoPointFourGhz {
+        return match width_mhz {
+            40 if primary + 4 <= 13 => Some(primary + 2),
+            40 if primary > 4 && primary <= 13 => Some(primary - 2),
+            _ => None,
+        };
+    }
+
+    // Bonded blocks are aligned to the first channel of their sub-band.
+    let base = match band {
+        WifiBand::FiveGhz if primary >= 149 => 149,
+        WifiBand::FiveGhz => 36,
+        _ => 1,
+    };
+    let span = width_mhz / 5;
+    let first = base + (primary - base) / span * span;
+    (first..first + span)
+        .step_by(4)
+        .all(|channel| band.is_20mhz_channel(channel))
+        .then_some(first + (span - 4) / 2)
+}
+
+/// Center frequency (Hz) of a Bluetooth BR/EDR channel 0–78.
+///
+/// f = 2402 MHz + k·1 MHz
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::channel::bluetooth_channel_to_frequency;
+/// assert_eq!(bluetooth_channel_to_frequency(0), Some(2402e6));
+/// assert_eq!(bluetooth_channel_to_frequency(78), Some(2480e6));
+/// assert_eq!(bluetooth_channel_to_frequency(79), None);
+/// ```
+#[doc(alias = "BR/EDR")]
+#[must_use]
+pub fn bluetooth_channel_to_frequency(channel: u32) -> Option<f64> {
+    (channel <= 78).then(|| 2402e6 + f64::from(channel) * 1e6)
+}
+
+/// Center frequency (Hz) of a Bluetooth Low Energy channel index 0–39.
+/// Data channels 0–36 skip the advertising channels 37 (2402 MHz),
+/// 38 (2426 MHz) and 39 (2480 MHz).
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::channel::ble_channel_to_frequency;
+/// assert_eq!(ble_channel_to_frequency(0), Some(2404e6));
+/// assert_eq!(ble_channel_to_frequency(11), Some(2428e6));
+/// assert_eq!(ble_channel_to_frequency(38), Some(2426e6));
+/// assert_eq!(ble_channel_to_frequency(40), None);
+/// ```
+#[doc(alias = "BLE")]
+#[must_use]
+pub fn ble_channel_to_frequency(index: u32) -> Option<f64> {
+    let mhz = match index {
+        0..=10 => 2404 + 2 * index,
+        11..=36 => 2428 + 2 * (index - 11),
+        37 => 2402,
+        38 => 2426,
+        39 => 2480,
+        _ => return None,
+    };
+    Some(f64::from(mhz) * 1e6)
+}
+
+/// Bluetooth Low Energy channel index of a center frequency (Hz).
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::channel::frequency_to_ble_channel;
+/// assert_eq!(frequency_to_ble_channel(2480e6), Some(39));
+/// assert_eq!(frequency_to_ble_channel(2403e6), None);
+/// ```
+#[doc(alias = "BLE")]
+#[must_use]
+pub fn frequency_to_ble_channel(frequency: f64) -> Option<u32> {
+    (0..40).find(|&index| ble_channel_to_frequency(index) == Some(frequency))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Link, WifiBand, LTE_BANDS};
+
+    #[test]
+    fn earfcn_round_trips_every_band_edge() {
+        for band in &LTE_BANDS {
+            for link in [Link::Downlink, Link::Uplink] {
+                let (first, last) = band.earfcn_range(link);
+                for earfcn in [first, last] {
+                    let f = super::earfcn_to_frequency(earfcn).unwrap();
+                    assert_eq!(
+                        super::frequency_to_earfcn(band.number, link, f),
+                        Some(earfcn)
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn earfcn_rejects_non_finite_and_huge_frequencies() {
+        for f in [f64::NAN, f64::INFINITY, 1e30] {
+            assert_eq!(super::frequency_to_earfcn(3, Link::Downlink, f), None);
+        }
+    }
+
+    #[test]
+    fn nr_arfcn_round_trips_across_rasters() {
+        for arfcn in [0, 599_999, 600_000, 2_016_666, 2_016_667, 3_279_165] {
+            let f = super::nr_arfcn_to_frequency(arfcn);
+            assert_eq!(super::frequency_to_nr_arfcn(f), arfcn);
+        }
+    }
+
+    #[test]
+    fn nr_arfcn_top_of_range_is_clamped() {
+        let arfcn = super::frequency_to_nr_arfcn(100e9);
+        assert_eq!(arfcn, 3_279_165);
+        assert!((super::nr_arfcn_to_frequency(arfcn) - 99_999.96e6).abs() < 1.0);
+    }
+
+    #[test]
+    fn gscn_round_trips_across_rasters() {
+        for gscn in (2..=26_639).step_by(97) {
+            let f = super::gscn_to_frequency(gscn);
+            assert_eq!(super::frequency_to_gscn(f), gscn);
+        }
+    }
+
+    #[test]
+    fn wifi_channels_round_trip() {
+        for (band, channels) in [
+            (WifiBand::TwoPointFourGhz, 1..=14),
+            (WifiBand::FiveGhz, 36..=177),
+            (WifiBand::SixGhz, 1..=233),
+        ] {
+            for channel in channels {
+                let f = super::wifi_channel_to_frequency(band, channel).unwrap();
+                assert_eq!(super::frequency_to_wifi_channel(f), Some((band, channel)));
+            }
+        }
+    }
+
+    #[test]
+    fn ble_channels_cover_the_2_mhz_grid() {
+        let mut freqs: Vec<f64> = (0..40)
+            .map(|i| super::ble_channel_to_frequency(i).unwrap())
+            .collect();
+        freqs.sort_by(f64::total_cmp);
+        for (k, f) in freqs.iter().enumerate() {
+            assert_eq!(*f, 2402e6 + 2e6 * k as f64);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "NR-ARFCN must not exceed 3279165")]
+    fn rejects_arfcn_above_100_ghz() {
+        let _ = super::nr_arfcn_to_frequency(3_279_166);
+    }
+}
@@ -0,0 +1,221 @@
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// A complex number, used for impedances (R + jX), admittances (G + jB),
+/// and reflection coefficients (Γ).
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::complex::Complex;
+/// let z = Complex::new(40.0, 30.0);
+/// let y = Complex::ONE / z;
+/// assert!((y.re - 0.016).abs() < 1e-15 && (y.im + 0.012).abs() < 1e-15);
+/// assert_eq!(z.norm(), 50.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Complex {
+    /// Real part.
+    pub re: f64,
+    /// Imaginary part.
+    pub im: f64,
+}
+
+impl Complex {
+    /// 0 + j0.
+    pub const ZERO: Complex = Complex { re: 0.0, im: 0.0 };
+    /// 1 + j0.
+    pub const ONE: Complex = Complex { re: 1.0, im: 0.0 };
+
+    /// Complex number from real and imaginary parts.
+    #[must_use]
+    pub const fn new(re: f64, im: f64) -> Self {
+        Complex { re, im }
+    }
+
+    /// Complex number from a magnitude and an angle in radians.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfconversions::complex::Complex;
+    /// let gamma = Complex::from_polar(0.5, std::f64::consts::FRAC_PI_2);
+    /// assert!(gamma.re.abs() < 1e-15 && (gamma.im - 0.5).abs() < 1e-15);
+    /// ```
+    #[must_use]
+    pub fn from_polar(magnitude: f64, angle: f64) -> Self {
+        Complex::new(magnitude * angle.cos(), magnitude * angle.sin())
+    }
+
+    /// Squared magnitude |z|².
+    #[must_use]
+    pub fn norm_sqr(self) -> f64 {
+        self.re * self.re + self.im * self.im
+    }
+
+    /// Magnitude |z|.
+    #[must_use]
+    pub fn norm(self) -> f64 {
+        self.re.hypot(self.im)
+    }
+
+    /// Angle in radians, in (−π, π].
+    #[must_use]
+    pub fn arg(self) -> f64 {
+        self.im.atan2(self.re)
+    }
+
+    /// Complex conjugate.
+    #[must_use]
+    pub fn conj(self) -> Self {
+        Complex::new(self.re, -self.im)
+    }
+
+    /// Principal square root.
+    #[must_use]
+    pub fn sqrt(self) -> Self {
+        let r = self.norm();
+        let re = ((r + self.re) / 2.0).sqrt();
+        let im = ((r - self.re) / 2.0).sqrt().copysign(self.im);
+        Complex { re, im }
+    }
+
+    /// Multiply by a real scalar.
+    #[must_use]
+    pub fn scale(self, k: f64) -> Self {
+        Complex {
+            re: self.re * k,
+            im: self.im * k,
+        }
+    }
+}
+
+impl From<f64> for Complex {
+    fn from(re: f64) -> Self {
+        Complex { re, im: 0.0 }
+    }
+}
+
+impl From<(f64, f64)> for Complex {
+    fn from((re, im): (f64, f64)) -> Self {
+        Complex { re, im }
+    }
+}
+
+impl From<Complex> for (f64, f64) {
+    fn from(z: Complex) -> Self {
+        (z.re, z.im)
+    }
+}
+
+impl Add for Complex {
+    type Output = Complex;
+    fn add(self, rhs: Complex) -> Complex {
+        Complex::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl Sub for Complex {
+    type Output = Complex;
+    fn sub(self, rhs: Complex) -> Complex {
+        Complex::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl Mul for Complex {
+    type Output = Complex;
+    fn mul(self, rhs: Complex) -> Complex {
+        Complex::new(
+            self.re * rhs.re - self.im * rhs.im,
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
+
+impl Div for Complex {
+    type Output = Complex;
+    fn div(self, rhs: Complex) -> Complex {
+        let den = rhs.norm_sqr();
+        Complex::new(
+            (self.re * rhs.re + self.im * rhs.im) / den,
+            (self.im * rhs.re - self.re * rhs.im) / den,
+        )
+    }
+}
+
+impl Neg for Complex {
+    type Output = Complex;
+    fn neg(self) -> Complex {
+        Complex::new(-self.re, -self.im)
+    }
+}
+
+/// Evaluate a real polynomial (ascending coefficients) at a complex point.
+pub(crate) fn polyval(coefficients: &[f64], z: Complex) -> Complex {
+    coefficients
+        .iter()
+        .rev()
+        .fold(Complex::ZERO, |acc, &c| acc * z + Complex::from(c))
+}
+
+/// All complex roots of a real polynomial (ascending coefficients) using the
+/// Durand–Kerner iteration.
+pub(crate) fn roots(coefficients: &[f64]) -> Vec<Complex> {
+    let degree = coefficients.len() - 1;
+    let lead = coefficients[degree];
+    let monic: Vec<f64> = coefficients.iter().map(|c| c / lead).collect();
+
+    let seed = Complex::new(0.4, 0.9);
+    let mut z: Vec<Complex> = Vec::with_capacity(degree);
+    let mut power = Complex::ONE;
+    for _ in 0..degree {
+        z.push(power);
+        power = power * seed;
+    }
+
+    for _ in 0..1000 {
+        let mut max_step: f64 = 0.0;
+        for i in 0..degree {
+            let mut den = Complex::ONE;
+            for (j, &zj) in z.iter().enumerate() {
+                if i != j {
+                    den = den * (z[i] - zj);
+                }
+            }
+            let step = polyval(&monic, z[i]) / den;
+            z[i] = z[i] - step;
+            max_step = max_step.max(step.norm());
+        }
+        if max_step < 1e-14 {
+            break;
+        }
+    }
+    z
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Complex;
+
+    #[test]
+    fn division_inverts_multiplication() {
+        let a = Complex::new(1.5, -2.0);
+        let b = Complex::new(-0.3, 0.7);
+        let c = a * b / b;
+        assert!((c - a).norm() < 1e-15);
+    }
+
+    #[test]
+    fn sqrt_of_negative_real() {
+        let r = Complex::from(-4.0).sqrt();
+        assert!((r - Complex::new(0.0, 2.0)).norm() < 1e-15);
+    }
+
+    #[test]
+    fn roots_of_quadratic() {
+        // s² + 2s + 5 = 0 → −1 ± j2
+        let mut r = super::roots(&[5.0, 2.0, 1.0]);
+        r.sort_by(|a, b| a.im.total_cmp(&b.im));
+        assert!((r[0] - Complex::new(-1.0, -2.0)).norm() < 1e-12);
+        assert!((r[1] - Complex::new(-1.0, 2.0)).norm() < 1e-12);
+    }
+}
@@ -0,0 +1,263 @@
+/// Number of lowest-drive points averaged for the small-signal gain.
+const SMALL_SIGNAL_POINTS: usize = 3;
+
+/// Small-signal gain (dB) of a measured sweep of `(input_dbm, output_dbm)`
+/// pairs: the mean gain of the three lowest-drive points.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::compression::small_signal_gain_db;
+/// let sweep = [(-20.0, 0.0), (-15.0, 5.0), (-10.0, 10.0), (-5.0, 14.5), (0.0, 17.0)];
+/// assert!((small_signal_gain_db(&sweep) - 20.0).abs() < 1e-12);
+/// ```
+///
+/// # Panics
+///
+/// Panics if the sweep has fewer than two points or its input powers are
+/// not strictly increasing.
+#[must_use]
+pub fn small_signal_gain_db(sweep: &[(f64, f64)]) -> f64 {
+    check_sweep(sweep);
+    let n = sweep.len().min(SMALL_SIGNAL_POINTS);
+    sweep[..n]
+        .iter()
+        .map(|&(pin, pout)| pout - pin)
+        .sum::<f64>()
+        / n as f64
+}
+
+/// Point `(input_dbm, output_dbm)` where the gain has dropped by
+/// `compression_db` below the small-signal gain, linearly interpolated
+/// between measurements. Returns `None` if the sweep never compresses that
+/// far.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::compression::compression_point;
+/// let sweep = [(-20.0, 0.0), (-15.0, 5.0), (-10.0, 10.0), (-5.0, 14.5), (0.0, 17.0)];
+/// // Gain is 19.5 dB at −5 dBm and 17 dB at 0 dBm → 1 dB down at −4 dBm
+/// let (pin, pout) = compression_point(&sweep, 1.0).unwrap();
+/// assert!((pin - (-4.0)).abs() < 1e-12);
+/// assert!((pout - 15.0).abs() < 1e-12);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `compression_db` is not positive or the sweep is invalid.
+#[must_use]
+pub fn compression_point(sweep: &[(f64, f64)], compression_db: f64) -> Option<(f64, f64)> {
+    assert!(compression_db > 0.0, "compression must be positive");
+    let target = small_signal_gain_db(sweep) - compression_db;
+
+    sweep.windows(2).find_map(|w| {
+        let (pin0, pout0) = w[0];
+        let (pin1, pout1) = w[1];
+        let (g0, g1) = (pout0 - pin0, pout1 - pin1);
+        if g0 > target && g1 <= target {
+            let pin = pin0 + (g0 - target) / (g0 - g1) * (pin1 - pin0);
+            Some((pin, pin + target))
+        } else {
+            None
+        }
+    })
+}
+
+/// 1 dB compression point `(input_dbm, output_dbm)`.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::compression::p1db;
+/// use rfconversions::p1db::output_to_input_db;
+/// let sweep = [(-20.0, 0.0), (-15.0, 5.0), (-10.0, 10.0), (-5.0, 14.5), (0.0, 17.0)];
+/// let (ip1db, op1db) = p1db(&sweep).unwrap();
+/// assert!((output_to_input_db(op1db, 20.0) - ip1db).abs() < 1e-12);
+/// ```
+///
+/// # Panics
+///
+/// Panics if the sweep is invalid.
+#[doc(alias = "P1dB")]
+#[must_use]
+pub fn p1db(sweep: &[(f64, f64)]) -> Option<(f64, f64)> {
+    compression_point(sweep, 1.0)
+}
+
+/// 2 dB compression point `(input_dbm, output_dbm)`.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::compression::p2db;
+/// let sweep = [(-20.0, 0.0), (-15.0, 5.0), (-10.0, 10.0), (-5.0, 14.5), (0.0, 17.0)];
+/// let (pin, pout) = p2db(&sweep).unwrap();
+/// assert!((pin - (-2.0)).abs() < 1e-12);
+/// assert!((pout - 16.0).abs() < 1e-12);
+/// ```
+///
+/// # Panics
+///
+/// Panics if the sweep is invalid.
+#[doc(alias = "P2dB")]
+#[must_use]
+pub fn p2db(sweep: &[(f64, f64)]) -> Option<(f64, f64)> {
+    compression_point(sweep, 2.0)
+}
+
+/// 3 dB compression point `(input_dbm, output_dbm)`.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::compression::p3db;
+/// let sweep = [(-20.0, 0.0), (-15.0, 5.0), (-10.0, 10.0), (-5.0, 14.5), (0.0, 17.0)];
+/// let (pin, pout) = p3db(&sweep).unwrap();
+/// assert!(pin.abs() < 1e-12);
+/// assert!((pout - 17.0).abs() < 1e-12);
+/// ```
+///
+/// # Panics
+///
+/// Panics if the sweep is invalid.
+#[doc(alias = "P3dB")]
+#[must_use]
+pub fn p3db(sweep: &[(f64, f64)]) -> Option<(f64, f64)> {
+    compression_point(sweep, 3.0)
+}
+
+/// Saturated output point `(input_dbm, output_dbm)`. An interior maximum is
+/// refined with a parabola through it and its neighbours; a maximum at the
+/// end of the sweep is returned as measured.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::compression::saturated_power;
+/// let sweep = [(0.0, 20.0), (5.0, 24.0), (10.0, 25.0), (15.0, 24.5)];
+/// let (pin, psat) = saturated_power(&sweep);
+/// assert!((pin - 10.8333).abs() < 1e-4);
+/// assert!((psat - 25.0208).abs() < 1e-4);
+/// ```
+///
+/// # Panics
+///
+/// Panics if the sweep is invalid.
+#[doc(alias = "Psat")]
+#[must_use]
+pub fn saturated_power(sweep: &[(f64, f64)]) -> (f64, f64) {
+    check_sweep(sweep);
+    let i = (0..sweep.len())
+        .max_by(|&a, &b| sweep[a].1.total_cmp(&sweep[b].1))
+        .expect("sweep is not empty");
+    if i == 0 || i == sweep.len() - 1 {
+        return sweep[i];
+    }
+
+    let (x0, y0) = sweep[i - 1];
+    let (x1, y1) = sweep[i];
+    let (x2, y2) = sweep[i + 1];
+    // Vertex of the parabola through the three points.
+    let d01 = (y1 - y0) / (x1 - x0);
+    let d12 = (y2 - y1) / (x2 - x1);
+    let a = (d12 - d01) / (x2 - x0);
+    if a >= 0.0 {
+        return sweep[i];
+    }
+    let b = d01 - a * (x0 + x1);
+    let x = -b / (2.0 * a);
+    let y = y1 + (x - x1) * (d01 + a * (x - x0));
+    (x, y)
+}
+
+/// Gain expansion: the input power (dBm) of peak gain and how far (dB) it
+/// rises above the small-signal gain, or `None` if it stays within
+/// `threshold_db`. Typical of class-AB amplifiers.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::compression::gain_expansion;
+/// let class_ab = [(-20.0, 0.0), (-15.0, 5.0), (-10.0, 10.0), (-5.0, 15.6), (0.0, 19.0)];
+/// let (pin, expansion) = gain_expansion(&class_ab, 0.1).unwrap();
+/// assert_eq!(pin, -5.0);
+/// assert!((expansion - 0.6).abs() < 1e-9);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `threshold_db` is negative or the sweep is invalid.
+#[must_use]
+pub fn gain_expansion(sweep: &[(f64, f64)], threshold_db: f64) -> Option<(f64, f64)> {
+    assert!(threshold_db >= 0.0, "threshold must not be negative");
+    let small_signal = small_signal_gain_db(sweep);
+    sweep
+        .iter()
+        .map(|&(pin, pout)| (pin, pout - pin - small_signal))
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+        .filter(|&(_, expansion)| expansion > threshold_db)
+}
+
+fn check_sweep(sweep: &[(f64, f64)]) {
+    assert!(sweep.len() >= 2, "sweep needs at least two points");
+    assert!(
+        sweep.windows(2).all(|w| w[1].0 > w[0].0),
+        "input powers must be strictly increasing"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::amplifier::Model;
+
+    fn rapp_sweep() -> (Model, Vec<(f64, f64)>) {
+        let pa = Model::Rapp {
+            gain_db: 25.0,
+            saturation_dbm: 35.0,
+            smoothness: 2.0,
+        };
+        let sweep = (-40..=40)
+            .map(|p| {
+                let pin = p as f64 * 0.5;
+                (pin, pa.output_dbm(pin))
+            })
+            .collect();
+        (pa, sweep)
+    }
+
+    #[test]
+    fn recovers_model_compression_points() {
+        let (pa, sweep) = rapp_sweep();
+        let (pin, pout) = super::p1db(&sweep).unwrap();
+        let (model_pin, model_pout) = pa.p1db().unwrap();
+        assert!((pin - model_pin).abs() < 0.05);
+        assert!((pout - model_pout).abs() < 0.05);
+        let (pin3, _) = super::p3db(&sweep).unwrap();
+        assert!((pin3 - pa.p3db().unwrap().0).abs() < 0.05);
+    }
+
+    #[test]
+    fn uncompressed_sweep_has_no_p1db() {
+        let sweep = [(-10.0, 0.0), (-5.0, 5.0), (0.0, 9.5)];
+        assert!(super::p1db(&sweep).is_none());
+    }
+
+    #[test]
+    fn monotonic_sweep_saturates_at_last_point() {
+        let (_, sweep) = rapp_sweep();
+        assert_eq!(super::saturated_power(&sweep), *sweep.last().unwrap());
+    }
+
+    #[test]
+    fn compressive_sweep_has_no_expansion() {
+        let (_, sweep) = rapp_sweep();
+        assert!(super::gain_expansion(&sweep, 0.05).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "input powers must be strictly increasing")]
+    fn rejects_unsorted_sweep() {
+        let _ = super::small_signal_gain_db(&[(0.0, 10.0), (-5.0, 5.0)]);
+    }
+}
@@ -32,6 +32,28 @@ pub const BOLTZMANN: f64 = 1.380649e-23;
 /// ```
 pub const T0: f64 = 290.0;
 
+/// Vacuum permeability μ₀ in henries per meter (H/m).
+///
+/// Used for conductor surface resistance in waveguide and transmission line losses.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::constants::VACUUM_PERMEABILITY;
+/// assert!((VACUUM_PERMEABILITY - 1.25663706212e-6).abs() < 1e-16);
+/// ```
+pub const VACUUM_PERMEABILITY: f64 = 1.25663706212e-6;
+
+/// Characteristic impedance of free space η₀ = μ₀·c, in ohms.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::constants::FREE_SPACE_IMPEDANCE;
+/// assert!((FREE_SPACE_IMPEDANCE - 376.730).abs() < 0.001);
+/// ```
+pub const FREE_SPACE_IMPEDANCE: f64 = 376.730313668;
+
 #[cfg(test)]
 mod tests {
 
@@ -52,4 +74,10 @@ mod tests {
         use super::T0;
         assert_eq!(290.0, T0);
     }
+
+    #[test]
+    fn free_space_impedance_is_mu0_times_c() {
+        use super::{FREE_SPACE_IMPEDANCE, SPEED_OF_LIGHT, VACUUM_PERMEABILITY};
+        assert!((FREE_SPACE_IMPEDANCE - VACUUM_PERMEABILITY * SPEED_OF_LIGHT).abs() < 1e-6);
+    }
 }
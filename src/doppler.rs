@@ -0,0 +1,251 @@
+use crate::constants::SPEED_OF_LIGHT;
+
+/// Mean Earth radius in meters.
+const EARTH_RADIUS: f64 = 6_371_000.0;
+
+/// Earth's standard gravitational parameter GM in m³/s².
+const EARTH_GM: f64 = 3.986_004_418e14;
+
+/// One-way Doppler shift (Hz) for a radial velocity (m/s), positive when
+/// the source and receiver approach each other.
+///
+/// Δf = f·v / c
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::doppler::doppler_shift;
+/// // 2.4 GHz seen from a car closing at 30 m/s
+/// assert!((doppler_shift(2.4e9, 30.0) - 240.17).abs() < 0.01);
+/// ```
+#[must_use]
+pub fn doppler_shift(frequency: f64, radial_velocity: f64) -> f64 {
+    frequency * radial_velocity / SPEED_OF_LIGHT
+}
+
+/// Relativistic one-way Doppler shift (Hz) for a radial velocity (m/s),
+/// positive when approaching.
+///
+/// Δf = f·(√((1 + β)/(1 − β)) − 1), β = v / c
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::doppler::{doppler_shift, relativistic_doppler_shift};
+/// // At 0.1c the classical result is 5 % low
+/// let v = 0.1 * 299_792_458.0;
+/// assert!((relativistic_doppler_shift(1e9, v) - 105.54e6).abs() < 0.01e6);
+/// assert!((doppler_shift(1e9, v) - 100e6).abs() < 1.0);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `|radial_velocity|` is not below the speed of light.
+#[must_use]
+pub fn relativistic_doppler_shift(frequency: f64, radial_velocity: f64) -> f64 {
+    assert!(
+        radial_velocity.abs() < SPEED_OF_LIGHT,
+        "radial velocity must be below the speed of light"
+    );
+    let beta = radial_velocity / SPEED_OF_LIGHT;
+    frequency * (((1.0 + beta) / (1.0 - beta)).sqrt() - 1.0)
+}
+
+/// Radial velocity (m/s) from a measured one-way Doppler shift (Hz),
+/// positive when approaching. Range rate is its negative.
+///
+/// v = c·Δf / f
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::doppler::radial_velocity_from_shift;
+/// assert!((radial_velocity_from_shift(2.4e9, 240.17) - 30.0).abs() < 0.01);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `frequency` is not positive.
+#[doc(alias = "range rate")]
+#[must_use]
+pub fn radial_velocity_from_shift(frequency: f64, shift: f64) -> f64 {
+    assert!(frequency > 0.0, "frequency must be positive");
+    SPEED_OF_LIGHT * shift / frequency
+}
+
+/// Two-way (monostatic radar) Doppler shift (Hz) of a target closing at a
+/// radial velocity (m/s).
+///
+/// Δf = 2·f·v / c
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::doppler::radar_doppler_shift;
+/// // X-band radar, target closing at 30 m/s
+/// assert!((radar_doppler_shift(10e9, 30.0) - 2001.38).abs() < 0.01);
+/// ```
+#[must_use]
+pub fn radar_doppler_shift(frequency: f64, radial_velocity: f64) -> f64 {
+    2.0 * doppler_shift(frequency, radial_velocity)
+}
+
+/// Target radial velocity (m/s) from a two-way radar Doppler shift (Hz).
+///
+/// v = c·Δf / (2·f)
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::doppler::radar_radial_velocity;
+/// assert!((radar_radial_velocity(10e9, 2001.38) - 30.0).abs() < 0.01);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `frequency` is not positive.
+#[must_use]
+pub fn radar_radial_velocity(frequency: f64, shift: f64) -> f64 {
+    radial_velocity_from_shift(frequency, shift) / 2.0
+}
+
+/// Doppler rate (Hz/s) from the rate of change of radial velocity (m/s²).
+///
+/// dΔf/dt = f·a / c
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::doppler::doppler_rate;
+/// assert!((doppler_rate(2.2e9, 100.0) - 733.84).abs() < 0.01);
+/// ```
+#[must_use]
+pub fn doppler_rate(frequency: f64, radial_acceleration: f64) -> f64 {
+    frequency * radial_acceleration / SPEED_OF_LIGHT
+}
+
+/// Circular orbital velocity (m/s) at an altitude (m) above a spherical
+/// Earth.
+///
+/// v = √(GM / (R + h))
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::doppler::orbital_velocity;
+/// assert!((orbital_velocity(500e3) - 7616.6).abs() < 0.1);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `altitude` is negative.
+#[must_use]
+pub fn orbital_velocity(altitude: f64) -> f64 {
+    assert!(altitude >= 0.0, "altitude must not be negative");
+    (EARTH_GM / (EARTH_RADIUS + altitude)).sqrt()
+}
+
+/// Maximum Doppler shift (Hz) seen by a ground station during an overhead
+/// pass of a satellite in a circular orbit, reached at the lowest usable
+/// elevation (degrees). Earth rotation is neglected.
+///
+/// Δf_max = f·v·R·cos ε / (c·(R + h))
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::doppler::max_leo_doppler_shift;
+/// // S-band downlink from 500 km, horizon to horizon
+/// assert!((max_leo_doppler_shift(2.2e9, 500e3, 0.0) - 51.83e3).abs() < 0.01e3);
+/// // ...with a 10° elevation mask
+/// assert!((max_leo_doppler_shift(2.2e9, 500e3, 10.0) - 51.04e3).abs() < 0.01e3);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `altitude` is negative or `min_elevation_deg` is outside
+/// 0–90°.
+#[must_use]
+pub fn max_leo_doppler_shift(frequency: f64, altitude: f64, min_elevation_deg: f64) -> f64 {
+    assert!(
+        (0.0..=90.0).contains(&min_elevation_deg),
+        "elevation must be within 0–90 degrees"
+    );
+    let v = orbital_velocity(altitude);
+    // Line-of-sight component of the velocity: v·sin(nadir angle).
+    let sin_nadir = EARTH_RADIUS * min_elevation_deg.to_radians().cos() / (EARTH_RADIUS + altitude);
+    doppler_shift(frequency, v * sin_nadir)
+}
+
+/// Maximum Doppler rate magnitude (Hz/s) during an overhead pass of a
+/// satellite in a circular orbit, reached at zenith where the shift
+/// crosses zero. Earth rotation is neglected.
+///
+/// |dΔf/dt|_max = f·R·v² / (c·(R + h)·h)
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::doppler::max_leo_doppler_rate;
+/// assert!((max_leo_doppler_rate(2.2e9, 500e3) - 789.5).abs() < 0.1);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `altitude` is not positive.
+#[must_use]
+pub fn max_leo_doppler_rate(frequency: f64, altitude: f64) -> f64 {
+    assert!(altitude > 0.0, "altitude must be positive");
+    let v = orbital_velocity(altitude);
+    doppler_rate(
+        frequency,
+        EARTH_RADIUS * v * v / ((EARTH_RADIUS + altitude) * altitude),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn relativistic_matches_classical_at_low_speed() {
+        let classical = super::doppler_shift(10e9, 300.0);
+        let relativistic = super::relativistic_doppler_shift(10e9, 300.0);
+        assert!((relativistic - classical).abs() < 0.01);
+    }
+
+    #[test]
+    fn relativistic_receding_is_not_antisymmetric() {
+        let v = 0.5 * crate::constants::SPEED_OF_LIGHT;
+        let approaching = super::relativistic_doppler_shift(1e9, v);
+        let receding = super::relativistic_doppler_shift(1e9, -v);
+        // f·(√3 − 1) and f·(1/√3 − 1)
+        assert!((approaching - 1e9 * (3f64.sqrt() - 1.0)).abs() < 1e-3);
+        assert!((receding - 1e9 * (1.0 / 3f64.sqrt() - 1.0)).abs() < 1e-3);
+    }
+
+    #[test]
+    fn radar_round_trip() {
+        let shift = super::radar_doppler_shift(35e9, -12.5);
+        assert!((super::radar_radial_velocity(35e9, shift) + 12.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn zenith_mask_gives_no_shift() {
+        assert!(super::max_leo_doppler_shift(2.2e9, 500e3, 90.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn higher_orbits_see_less_doppler() {
+        let low = super::max_leo_doppler_shift(2.2e9, 400e3, 0.0);
+        let high = super::max_leo_doppler_shift(2.2e9, 1200e3, 0.0);
+        assert!(low > high);
+        assert!(
+            super::max_leo_doppler_rate(2.2e9, 400e3) > super::max_leo_doppler_rate(2.2e9, 1200e3)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "radial velocity must be below the speed of light")]
+    fn rejects_superluminal_velocity() {
+        let _ = super::relativistic_doppler_shift(1e9, 3e8);
+    }
+}
@@ -0,0 +1,229 @@
+use crate::power::{db_to_linear, dbm_to_watts};
+
+/// Idealized amplifier class for efficiency at backoff.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Class {
+    /// Class A: 50 % peak efficiency, falling with output power.
+    A,
+    /// Class B: π/4 (78.5 %) peak efficiency, falling with output voltage.
+    B,
+}
+
+impl Class {
+    /// Peak (full-swing) drain efficiency in percent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfconversions::efficiency::Class;
+    /// assert_eq!(Class::A.max_efficiency_percent(), 50.0);
+    /// assert!((Class::B.max_efficiency_percent() - 78.54).abs() < 0.01);
+    /// ```
+    #[must_use]
+    pub fn max_efficiency_percent(&self) -> f64 {
+        match self {
+            Class::A => 50.0,
+            Class::B => 25.0 * std::f64::consts::PI,
+        }
+    }
+}
+
+/// DC supply power in watts.
+///
+/// P_DC = V·I
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::efficiency::dc_power;
+/// assert_eq!(dc_power(28.0, 2.5), 70.0);
+/// ```
+#[must_use]
+pub fn dc_power(voltage: f64, current: f64) -> f64 {
+    voltage * current
+}
+
+/// Drain (collector) efficiency in percent.
+///
+/// η = P_out / P_DC
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::efficiency::drain_efficiency_percent;
+/// // 20 W (43 dBm) out of 50 W DC
+/// assert!((drain_efficiency_percent(43.0103, 50.0) - 40.0).abs() < 1e-3);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `dc_power` is not positive.
+#[doc(alias = "collector efficiency")]
+#[must_use]
+pub fn drain_efficiency_percent(output_dbm: f64, dc_power: f64) -> f64 {
+    assert!(dc_power > 0.0, "DC power must be positive");
+    100.0 * dbm_to_watts(output_dbm) / dc_power
+}
+
+/// Power-added efficiency in percent.
+///
+/// PAE = (P_out − P_in) / P_DC
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::efficiency::power_added_efficiency_percent;
+/// // 20 W out, 1 W in, 50 W DC
+/// let pae = power_added_efficiency_percent(43.0103, 30.0, 50.0);
+/// assert!((pae - 38.0).abs() < 1e-3);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `dc_power` is not positive.
+#[doc(alias = "PAE")]
+#[must_use]
+pub fn power_added_efficiency_percent(output_dbm: f64, input_dbm: f64, dc_power: f64) -> f64 {
+    assert!(dc_power > 0.0, "DC power must be positive");
+    100.0 * (dbm_to_watts(output_dbm) - dbm_to_watts(input_dbm)) / dc_power
+}
+
+/// DC power (W) drawn to deliver an output power at a drain efficiency.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::efficiency::dc_power_from_efficiency;
+/// assert!((dc_power_from_efficiency(40.0, 25.0) - 40.0).abs() < 1e-12);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `efficiency_percent` is outside (0, 100].
+#[must_use]
+pub fn dc_power_from_efficiency(output_dbm: f64, efficiency_percent: f64) -> f64 {
+    assert!(
+        efficiency_percent > 0.0 && efficiency_percent <= 100.0,
+        "efficiency must be in (0, 100] percent"
+    );
+    dbm_to_watts(output_dbm) / (efficiency_percent / 100.0)
+}
+
+/// Power (W) dissipated as heat: everything that goes in and does not come
+/// out as RF.
+///
+/// P_diss = P_DC + P_in − P_out
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::efficiency::dissipated_power;
+/// let p = dissipated_power(43.0103, 30.0, 50.0);
+/// assert!((p - 31.0).abs() < 1e-3);
+/// ```
+#[must_use]
+pub fn dissipated_power(output_dbm: f64, input_dbm: f64, dc_power: f64) -> f64 {
+    dc_power + dbm_to_watts(input_dbm) - dbm_to_watts(output_dbm)
+}
+
+/// Junction (channel) temperature in °C from dissipated power (W), the
+/// junction-to-case thermal resistance (°C/W) and the base-plate
+/// temperature (°C).
+///
+/// T_j = T_base + P_diss·θ_jc
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::efficiency::junction_temperature;
+/// assert_eq!(junction_temperature(31.0, 2.5, 70.0), 147.5);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `thermal_resistance` is negative.
+#[doc(alias = "channel temperature")]
+#[must_use]
+pub fn junction_temperature(
+    dissipated_power: f64,
+    thermal_resistance: f64,
+    base_plate_temperature: f64,
+) -> f64 {
+    assert!(
+        thermal_resistance >= 0.0,
+        "thermal resistance must not be negative"
+    );
+    base_plate_temperature + dissipated_power * thermal_resistance
+}
+
+/// Ideal drain efficiency (%) at an output backoff (dB) from full swing.
+///
+/// Class A: η = 50 %·10^(−OBO/10); class B: η = 78.5 %·10^(−OBO/20)
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::efficiency::{efficiency_at_backoff_percent, Class};
+/// // 6 dB backoff
+/// assert!((efficiency_at_backoff_percent(Class::A, 6.0) - 12.56).abs() < 0.01);
+/// assert!((efficiency_at_backoff_percent(Class::B, 6.0) - 39.36).abs() < 0.01);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `backoff_db` is negative.
+#[must_use]
+pub fn efficiency_at_backoff_percent(class: Class, backoff_db: f64) -> f64 {
+    assert!(backoff_db >= 0.0, "backoff must not be negative");
+    let power_ratio = db_to_linear(-backoff_db);
+    match class {
+        // Constant DC draw: efficiency follows output power.
+        Class::A => class.max_efficiency_percent() * power_ratio,
+        // DC draw tracks output voltage: efficiency follows amplitude.
+        Class::B => class.max_efficiency_percent() * power_ratio.sqrt(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Class;
+
+    #[test]
+    fn pae_approaches_drain_efficiency_at_high_gain() {
+        let de = super::drain_efficiency_percent(40.0, 30.0);
+        let pae = super::power_added_efficiency_percent(40.0, 0.0, 30.0);
+        assert!((de - pae) < 0.01 && de > pae);
+    }
+
+    #[test]
+    fn efficiency_round_trips_dc_power() {
+        let pdc = super::dc_power_from_efficiency(37.0, 42.0);
+        assert!((super::drain_efficiency_percent(37.0, pdc) - 42.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn energy_balance() {
+        // P_DC + P_in = P_out + P_diss
+        let (pout, pin, pdc) = (46.0, 33.0, 100.0);
+        let diss = super::dissipated_power(pout, pin, pdc);
+        let out = crate::power::dbm_to_watts(pout);
+        let input = crate::power::dbm_to_watts(pin);
+        assert!((pdc + input - out - diss).abs() < 1e-12);
+    }
+
+    #[test]
+    fn full_swing_gives_peak_efficiency() {
+        for class in [Class::A, Class::B] {
+            assert_eq!(
+                super::efficiency_at_backoff_percent(class, 0.0),
+                class.max_efficiency_percent()
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "efficiency must be in (0, 100] percent")]
+    fn rejects_efficiency_above_100_percent() {
+        let _ = super::dc_power_from_efficiency(30.0, 120.0);
+    }
+}
@@ -0,0 +1,341 @@
+/// Gain compression at the 1 dB compression point, so IP1dB = OP1dB − G + 1.
+const GAIN_COMPRESSION_AT_P1DB_DB: f64 = 1.0;
+/// 10·log₁₀((4/3) / 0.145): IIP3 above IP1dB for a cubic nonlinearity.
+const IIP3_ABOVE_IP1DB: f64 = 9.636;
+
+/// Convert RMS EVM in percent to dB.
+///
+/// EVM(dB) = 20·log₁₀(EVM% / 100)
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::evm::evm_percent_to_db;
+/// assert!((evm_percent_to_db(1.0) - (-40.0)).abs() < 1e-12);
+/// ```
+#[doc(alias = "EVM")]
+#[must_use]
+pub fn evm_percent_to_db(evm_percent: f64) -> f64 {
+    20.0 * (evm_percent / 100.0).log10()
+}
+
+/// Convert RMS EVM in dB to percent.
+///
+/// EVM% = 100·10^(EVM(dB)/20)
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::evm::evm_db_to_percent;
+/// assert!((evm_db_to_percent(-30.0) - 3.1623).abs() < 1e-4);
+/// ```
+#[doc(alias = "EVM")]
+#[must_use]
+pub fn evm_db_to_percent(evm_db: f64) -> f64 {
+    100.0 * 10.0_f64.powf(evm_db / 20.0)
+}
+
+/// Modulation error ratio (dB) from RMS EVM in percent.
+///
+/// MER = −20·log₁₀(EVM% / 100), with EVM normalized to the average
+/// constellation power.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::evm::mer_from_evm_percent;
+/// assert!((mer_from_evm_percent(2.0) - 33.979).abs() < 1e-3);
+/// ```
+#[doc(alias = "MER")]
+#[must_use]
+pub fn mer_from_evm_percent(evm_percent: f64) -> f64 {
+    -evm_percent_to_db(evm_percent)
+}
+
+/// RMS EVM in percent from MER (dB).
+///
+/// EVM% = 100·10^(−MER/20)
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::evm::evm_percent_from_mer;
+/// assert!((evm_percent_from_mer(40.0) - 1.0).abs() < 1e-12);
+/// ```
+#[doc(alias = "MER")]
+#[must_use]
+pub fn evm_percent_from_mer(mer_db: f64) -> f64 {
+    evm_db_to_percent(-mer_db)
+}
+
+/// RMS EVM in percent produced by additive noise at an SNR (dB).
+///
+/// With only AWGN present and a data-aided reference, MER equals SNR, so
+/// EVM% = 100·10^(−SNR/20).
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::evm::evm_percent_from_snr;
+/// assert!((evm_percent_from_snr(30.0) - 3.1623).abs() < 1e-4);
+/// ```
+#[doc(alias = "SNR")]
+#[must_use]
+pub fn evm_percent_from_snr(snr_db: f64) -> f64 {
+    evm_percent_from_mer(snr_db)
+}
+
+/// Equivalent SNR (dB) from RMS EVM in percent, treating all error as
+/// noise.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::evm::snr_from_evm_percent;
+/// assert!((snr_from_evm_percent(10.0) - 20.0).abs() < 1e-12);
+/// ```
+#[doc(alias = "SNR")]
+#[must_use]
+pub fn snr_from_evm_percent(evm_percent: f64) -> f64 {
+    mer_from_evm_percent(evm_percent)
+}
+
+/// RMS EVM in percent caused by random phase error (phase noise) of a given
+/// RMS value in degrees.
+///
+/// EVM² = 2·(1 − e^(−σ²/2)) ≈ σ² for small σ (radians)
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::evm::evm_percent_from_phase_error;
+/// // 1° RMS → 1.745 %
+/// assert!((evm_percent_from_phase_error(1.0) - 1.745).abs() < 1e-3);
+/// ```
+#[doc(alias = "phase noise")]
+#[must_use]
+pub fn evm_percent_from_phase_error(rms_phase_error_deg: f64) -> f64 {
+    let sigma = rms_phase_error_deg.to_radians();
+    100.0 * (-2.0 * (-sigma * sigma / 2.0).exp_m1()).sqrt()
+}
+
+/// Image rejection ratio (dB) of a quadrature modulator or demodulator
+/// with gain and phase imbalance.
+///
+/// IRR = (1 + 2g·cos φ + g²) / (1 − 2g·cos φ + g²), g = 10^(ΔG/20)
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::evm::image_rejection_db;
+/// // 0.1 dB and 1° → about 40 dB
+/// let irr = image_rejection_db(0.1, 1.0);
+/// assert!((irr - 40.0).abs() < 0.5);
+/// ```
+#[doc(alias = "IRR")]
+#[doc(alias = "IQ imbalance")]
+#[must_use]
+pub fn image_rejection_db(gain_imbalance_db: f64, phase_imbalance_deg: f64) -> f64 {
+    let g = 10.0_f64.powf(gain_imbalance_db / 20.0);
+    let c = 2.0 * g * phase_imbalance_deg.to_radians().cos();
+    10.0 * ((1.0 + c + g * g) / (1.0 - c + g * g)).log10()
+}
+
+/// RMS EVM in percent caused by IQ gain and phase imbalance.
+///
+/// The image term sits at −IRR relative to the wanted signal, so
+/// EVM% = 100·10^(−IRR/20).
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::evm::evm_percent_from_iq_imbalance;
+/// let evm = evm_percent_from_iq_imbalance(0.1, 1.0);
+/// assert!(evm > 0.9 && evm < 1.1);
+/// ```
+#[doc(alias = "IQ imbalance")]
+#[must_use]
+pub fn evm_percent_from_iq_imbalance(gain_imbalance_db: f64, phase_imbalance_deg: f64) -> f64 {
+    evm_percent_from_mer(image_rejection_db(gain_imbalance_db, phase_imbalance_deg))
+}
+
+/// RMS EVM in percent from amplifier compression at an output power below
+/// the output P1dB.
+///
+/// Third-order estimate: a cubic amplifier has IIP3 = IP1dB + 9.64 dB, and
+/// the input backoff is OP1dB − Pout + 1 dB, so the distortion sits at
+/// 2·(backoff + 9.64) dBc.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::evm::evm_percent_from_compression;
+/// // 10 dB below OP1dB → IM3 at −41.3 dBc → ~0.86 %
+/// let evm = evm_percent_from_compression(20.0, 30.0);
+/// assert!((evm - 0.86).abs() < 0.01);
+/// ```
+#[doc(alias = "backoff")]
+#[doc(alias = "P1dB")]
+#[must_use]
+pub fn evm_percent_from_compression(output_power_dbm: f64, output_p1db_dbm: f64) -> f64 {
+    // IP1dB − Pin with Pin = Pout − G and IP1dB = OP1dB − G + 1.
+    let input_backoff = output_p1db_dbm + GAIN_COMPRESSION_AT_P1DB_DB - output_power_dbm;
+    let distortion_dbc = 2.0 * (input_backoff + IIP3_ABOVE_IP1DB);
+    evm_percent_from_mer(distortion_dbc)
+}
+
+/// Root-sum-square of independent EVM contributions in percent.
+///
+/// EVM = √(Σ EVMᵢ²)
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::evm::rss_evm_percent;
+/// assert!((rss_evm_percent(&[3.0, 4.0]) - 5.0).abs() < 1e-12);
+/// ```
+#[doc(alias = "RSS")]
+#[must_use]
+pub fn rss_evm_percent(contributions: &[f64]) -> f64 {
+    contributions.iter().map(|e| e * e).sum::<f64>().sqrt()
+}
+
+/// Transmitter or receiver EVM impairment budget.
+///
+/// Each impairment is converted to an RMS EVM contribution and the total
+/// is their root-sum-square.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EvmBudget {
+    /// Integrated RMS phase error of the LO in degrees.
+    pub rms_phase_error_deg: f64,
+    /// Thermal SNR in dB.
+    pub snr_db: f64,
+    /// IQ gain imbalance in dB.
+    pub iq_gain_imbalance_db: f64,
+    /// IQ phase imbalance in degrees.
+    pub iq_phase_imbalance_deg: f64,
+    /// Operating output power in dBm.
+    pub output_power_dbm: f64,
+    /// Amplifier output P1dB in dBm.
+    pub output_p1db_dbm: f64,
+}
+
+impl EvmBudget {
+    /// Individual contributions in percent: phase noise, thermal, IQ
+    /// imbalance and compression.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfconversions::evm::EvmBudget;
+    /// let budget = EvmBudget {
+    ///     rms_phase_error_deg: 0.5,
+    ///     snr_db: 35.0,
+    ///     iq_gain_imbalance_db: 0.05,
+    ///     iq_phase_imbalance_deg: 0.5,
+    ///     output_power_dbm: 20.0,
+    ///     output_p1db_dbm: 30.0,
+    /// };
+    /// let [phase, thermal, iq, compression] = budget.contributions();
+    /// assert!(thermal > phase && thermal > iq && thermal > compression);
+    /// ```
+    #[must_use]
+    pub fn contributions(&self) -> [f64; 4] {
+        [
+            evm_percent_from_phase_error(self.rms_phase_error_deg),
+            evm_percent_from_snr(self.snr_db),
+            evm_percent_from_iq_imbalance(self.iq_gain_imbalance_db, self.iq_phase_imbalance_deg),
+            evm_percent_from_compression(self.output_power_dbm, self.output_p1db_dbm),
+        ]
+    }
+
+    /// Total RMS EVM in percent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfconversions::evm::EvmBudget;
+    /// let budget = EvmBudget {
+    ///     rms_phase_error_deg: 0.5,
+    ///     snr_db: 35.0,
+    ///     iq_gain_imbalance_db: 0.05,
+    ///     iq_phase_imbalance_deg: 0.5,
+    ///     output_power_dbm: 20.0,
+    ///     output_p1db_dbm: 30.0,
+    /// };
+    /// assert!((budget.total_percent() - 2.22).abs() < 0.01);
+    /// ```
+    #[must_use]
+    pub fn total_percent(&self) -> f64 {
+        rss_evm_percent(&self.contributions())
+    }
+
+    /// Total EVM expressed as MER (dB).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfconversions::evm::EvmBudget;
+    /// let budget = EvmBudget {
+    ///     rms_phase_error_deg: 0.0,
+    ///     snr_db: 30.0,
+    ///     iq_gain_imbalance_db: 0.0,
+    ///     iq_phase_imbalance_deg: 0.0,
+    ///     output_power_dbm: -100.0,
+    ///     output_p1db_dbm: 30.0,
+    /// };
+    /// assert!((budget.total_mer_db() - 30.0).abs() < 1e-6);
+    /// ```
+    #[must_use]
+    pub fn total_mer_db(&self) -> f64 {
+        mer_from_evm_percent(self.total_percent())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn percent_db_round_trip() {
+        for evm in [0.1, 1.0, 5.6, 25.0] {
+            let back = super::evm_db_to_percent(super::evm_percent_to_db(evm));
+            assert!((back - evm).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn mer_and_snr_agree_for_awgn() {
+        assert!(
+            (super::mer_from_evm_percent(super::evm_percent_from_snr(27.0)) - 27.0).abs() < 1e-12
+        );
+        assert!(
+            (super::snr_from_evm_percent(3.0) - super::mer_from_evm_percent(3.0)).abs() < 1e-12
+        );
+    }
+
+    #[test]
+    fn phase_error_small_angle() {
+        // For small σ, EVM ≈ σ in radians.
+        let evm = super::evm_percent_from_phase_error(0.1);
+        assert!((evm - 100.0 * 0.1_f64.to_radians()).abs() < 1e-6);
+    }
+
+    #[test]
+    fn perfect_iq_balance_has_no_image() {
+        assert!(super::image_rejection_db(0.0, 0.0).is_infinite());
+        assert_eq!(super::evm_percent_from_iq_imbalance(0.0, 0.0), 0.0);
+    }
+
+    #[test]
+    fn compression_evm_drops_2x_per_db_backoff() {
+        // Distortion in dBc rises 2 dB per dB of backoff.
+        let a = super::evm_percent_to_db(super::evm_percent_from_compression(20.0, 30.0));
+        let b = super::evm_percent_to_db(super::evm_percent_from_compression(19.0, 30.0));
+        assert!((a - b - 2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn cubic_ip3_offset_constant() {
+        let offset = 20.0 * ((4.0_f64 / 3.0).sqrt() / 0.145_f64.sqrt()).log10();
+        assert!((offset - super::IIP3_ABOVE_IP1DB).abs() < 1e-3);
+    }
+}
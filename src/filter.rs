@@ -0,0 +1,1004 @@
+use std::f64::consts::PI;
+
+use crate::complex::{self, Complex};
+
+/// Lowpass prototype approximation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Approximation {
+    /// Maximally flat magnitude, 3 dB down at the cutoff.
+    Butterworth,
+    /// Equiripple passband; the cutoff is the ripple band edge.
+    Chebyshev {
+        /// Passband ripple in dB.
+        ripple_db: f64,
+    },
+    /// Maximally flat group delay, renormalized to be 3 dB down at the cutoff.
+    Bessel,
+    /// Equiripple passband and stopband (Cauer); the cutoff is the ripple
+    /// band edge. Odd orders only.
+    Elliptic {
+        /// Passband ripple in dB.
+        ripple_db: f64,
+        /// Minimum stopband attenuation in dB.
+        stopband_db: f64,
+    },
+}
+
+/// Normalized lowpass ladder prototype (1 Ω source, 1 rad/s cutoff).
+///
+/// The ladder starts with a shunt capacitor `g[0]`, followed by a series
+/// inductor `g[1]`, and alternates from there. Elliptic prototypes place a
+/// capacitor `resonators[k]` in parallel with each series inductor to create
+/// the finite transmission zeros; for all other approximations the
+/// resonators are zero.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Prototype {
+    /// Element values g₁ … gₙ.
+    pub g: Vec<f64>,
+    /// Capacitors in parallel with each series inductor (zero if absent).
+    pub resonators: Vec<f64>,
+    /// gₙ₊₁: load resistance if gₙ is a shunt capacitor, load conductance if
+    /// gₙ is a series inductor.
+    pub load: f64,
+}
+
+impl Prototype {
+    /// Filter order (number of g-values).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfconversions::filter::{self, Approximation};
+    /// assert_eq!(filter::prototype(Approximation::Butterworth, 5).unwrap().order(), 5);
+    /// ```
+    #[must_use]
+    pub fn order(&self) -> usize {
+        self.g.len()
+    }
+
+    /// Load resistance normalized to the 1 Ω source.
+    fn load_resistance(&self) -> f64 {
+        if self.order() % 2 == 1 {
+            self.load
+        } else {
+            1.0 / self.load
+        }
+    }
+}
+
+/// Frequency response a prototype is transformed to.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FilterResponse {
+    /// Lowpass with the given cutoff (Hz).
+    Lowpass {
+        /// Cutoff frequency in Hz.
+        cutoff: f64,
+    },
+    /// Highpass with the given cutoff (Hz).
+    Highpass {
+        /// Cutoff frequency in Hz.
+        cutoff: f64,
+    },
+    /// Bandpass around a geometric center frequency.
+    Bandpass {
+        /// Geometric center frequency in Hz.
+        center: f64,
+        /// Passband width in Hz.
+        bandwidth: f64,
+    },
+    /// Bandstop around a geometric center frequency.
+    Bandstop {
+        /// Geometric center frequency in Hz.
+        center: f64,
+        /// Stopband width (between cutoff edges) in Hz.
+        bandwidth: f64,
+    },
+}
+
+/// Denormalized ladder element (henries and farads), listed from source to
+/// load.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Element {
+    /// Inductor in the signal path.
+    SeriesInductor(f64),
+    /// Capacitor in the signal path.
+    SeriesCapacitor(f64),
+    /// Inductor from the signal path to ground.
+    ShuntInductor(f64),
+    /// Capacitor from the signal path to ground.
+    ShuntCapacitor(f64),
+    /// Series-resonant L–C in the signal path.
+    SeriesLc {
+        /// Inductance in henries.
+        inductance: f64,
+        /// Capacitance in farads.
+        capacitance: f64,
+    },
+    /// Parallel-resonant L‖C tank in the signal path.
+    SeriesTank {
+        /// Inductance in henries.
+        inductance: f64,
+        /// Capacitance in farads.
+        capacitance: f64,
+    },
+    /// Series-resonant L–C from the signal path to ground.
+    ShuntLc {
+        /// Inductance in henries.
+        inductance: f64,
+        /// Capacitance in farads.
+        capacitance: f64,
+    },
+    /// Parallel-resonant L‖C tank from the signal path to ground.
+    ShuntTank {
+        /// Inductance in henries.
+        inductance: f64,
+        /// Capacitance in farads.
+        capacitance: f64,
+    },
+    /// L‖C tank in parallel with a series-resonant L–C branch, in the
+    /// signal path: an elliptic series tank after a bandpass or bandstop
+    /// transformation.
+    SeriesResonatorPair {
+        /// Tank inductance in henries.
+        tank_inductance: f64,
+        /// Tank capacitance in farads.
+        tank_capacitance: f64,
+        /// Branch inductance in henries.
+        branch_inductance: f64,
+        /// Branch capacitance in farads.
+        branch_capacitance: f64,
+    },
+}
+
+/// Lowpass prototype g-values for an approximation and order.
+///
+/// Butterworth and Chebyshev values use the closed-form expressions:
+///
+/// Butterworth: gₖ = 2·sin((2k − 1)π / 2n)
+///
+/// Chebyshev: g₁ = 2a₁/γ, gₖ = 4aₖ₋₁aₖ / (bₖ₋₁gₖ₋₁), with
+/// aₖ = sin((2k − 1)π / 2n), bₖ = γ² + sin²(kπ / n),
+/// β = ln coth(ripple / 17.37), γ = sinh(β / 2n)
+///
+/// Bessel and elliptic prototypes are synthesized from their transfer
+/// functions by continued-fraction expansion of the input admittance, with
+/// zero shifting for the elliptic transmission zeros.
+///
+/// Returns `None` if an elliptic response has no ladder realization with
+/// all-positive elements in this topology, which happens for a small
+/// ripple with a shallow stopband (e.g. 5th order, 0.1 dB, 10 dB). The
+/// other approximations always return `Some`.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::filter::{self, Approximation};
+///
+/// let p = filter::prototype(Approximation::Chebyshev { ripple_db: 0.5 }, 3).unwrap();
+/// assert!((p.g[0] - 1.5963).abs() < 1e-4);
+/// assert!((p.g[1] - 1.0967).abs() < 1e-4);
+///
+/// let shallow = Approximation::Elliptic { ripple_db: 0.1, stopband_db: 10.0 };
+/// assert!(filter::prototype(shallow, 5).is_none());
+/// ```
+///
+/// # Panics
+///
+/// Panics if `order` is zero, if a ripple or stopband attenuation is not
+/// positive, if a Bessel order exceeds 10, or if an elliptic order is even
+/// or outside 3–9.
+#[doc(alias = "g-values")]
+#[doc(alias = "ladder prototype")]
+#[must_use]
+pub fn prototype(approximation: Approximation, order: usize) -> Option<Prototype> {
+    assert!(order >= 1, "order must be at least 1");
+    match approximation {
+        Approximation::Butterworth => Some(butterworth(order)),
+        Approximation::Chebyshev { ripple_db } => {
+            assert!(ripple_db > 0.0, "ripple must be positive");
+            Some(chebyshev(order, ripple_db))
+        }
+        Approximation::Bessel => {
+            assert!(order <= 10, "Bessel prototypes are limited to order 10");
+            Some(bessel(order))
+        }
+        Approximation::Elliptic {
+            ripple_db,
+            stopband_db,
+        } => {
+            assert!(
+                order % 2 == 1 && (3..=9).contains(&order),
+                "elliptic prototypes require an odd order from 3 to 9"
+            );
+            assert!(ripple_db > 0.0, "ripple must be positive");
+            assert!(
+                stopband_db > ripple_db,
+                "stopband attenuation must exceed the passband ripple"
+            );
+            elliptic(order, ripple_db, stopband_db)
+        }
+    }
+}
+
+/// Scale a prototype to real component values at a response and impedance.
+///
+/// Lowpass:  C = g / (Z₀ω_c), L = g·Z₀ / ω_c
+///
+/// Highpass: L = Z₀ / (g·ω_c), C = 1 / (g·Z₀ω_c)
+///
+/// Bandpass (Δ = BW / f₀): shunt C → shunt L‖C tank, series L → series L–C
+///
+/// Bandstop: shunt C → shunt L–C, series L → series L‖C tank
+///
+/// An elliptic series tank maps to a [`Element::SeriesResonatorPair`] in
+/// both cases, since its inductor and capacitor transform separately.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::filter::{self, Approximation, Element, FilterResponse};
+///
+/// let p = filter::prototype(Approximation::Butterworth, 3).unwrap();
+/// let elements = filter::transform(&p, FilterResponse::Lowpass { cutoff: 1e9 }, 50.0);
+/// match elements[1] {
+///     Element::SeriesInductor(l) => assert!((l - 15.915e-9).abs() < 1e-12),
+///     _ => unreachable!(),
+/// }
+/// ```
+///
+/// # Panics
+///
+/// Panics if the impedance or any frequency is not positive.
+#[doc(alias = "denormalize")]
+#[doc(alias = "frequency transformation")]
+#[must_use]
+pub fn transform(prototype: &Prototype, response: FilterResponse, impedance: f64) -> Vec<Element> {
+    assert!(impedance > 0.0, "impedance must be positive");
+
+    prototype
+        .g
+        .iter()
+        .zip(&prototype.resonators)
+        .enumerate()
+        .map(|(k, (&g, &c))| {
+            let shunt = k % 2 == 0;
+            match response {
+                FilterResponse::Lowpass { cutoff } => {
+                    assert!(cutoff > 0.0, "cutoff must be positive");
+                    let w = 2.0 * PI * cutoff;
+                    if shunt {
+                        Element::ShuntCapacitor(g / (impedance * w))
+                    } else if c > 0.0 {
+                        Element::SeriesTank {
+                            inductance: g * impedance / w,
+                            capacitance: c / (impedance * w),
+                        }
+                    } else {
+                        Element::SeriesInductor(g * impedance / w)
+                    }
+                }
+                FilterResponse::Highpass { cutoff } => {
+                    assert!(cutoff > 0.0, "cutoff must be positive");
+                    let w = 2.0 * PI * cutoff;
+                    if shunt {
+                        Element::ShuntInductor(impedance / (g * w))
+                    } else if c > 0.0 {
+                        Element::SeriesTank {
+                            inductance: impedance / (c * w),
+                            capacitance: 1.0 / (g * impedance * w),
+                        }
+                    } else {
+                        Element::SeriesCapacitor(1.0 / (g * impedance * w))
+                    }
+                }
+                FilterResponse::Bandpass { center, bandwidth } => {
+                    let (w0, delta) = band(center, bandwidth);
+                    if shunt {
+                        Element::ShuntTank {
+                            inductance: impedance * delta / (w0 * g),
+                            capacitance: g / (impedance * w0 * delta),
+                        }
+                    } else if c > 0.0 {
+                        Element::SeriesResonatorPair {
+                            tank_inductance: impedance * delta / (w0 * c),
+                            tank_capacitance: c / (impedance * w0 * delta),
+                            branch_inductance: g * impedance / (w0 * delta),
+                            branch_capacitance: delta / (w0 * g * impedance),
+                        }
+                    } else {
+                        Element::SeriesLc {
+                            inductance: g * impedance / (w0 * delta),
+                            capacitance: delta / (w0 * g * impedance),
+                        }
+                    }
+                }
+                FilterResponse::Bandstop { center, bandwidth } => {
+                    let (w0, delta) = band(center, bandwidth);
+                    if shunt {
+                        Element::ShuntLc {
+                            inductance: impedance / (w0 * g * delta),
+                            capacitance: g * delta / (w0 * impedance),
+                        }
+                    } else if c > 0.0 {
+                        Element::SeriesResonatorPair {
+                            tank_inductance: g * impedance * delta / w0,
+                            tank_capacitance: 1.0 / (w0 * g * impedance * delta),
+                            branch_inductance: impedance / (w0 * c * delta),
+                            branch_capacitance: c * delta / (w0 * impedance),
+                        }
+                    } else {
+                        Element::SeriesTank {
+                            inductance: g * impedance * delta / w0,
+                            capacitance: 1.0 / (w0 * g * impedance * delta),
+                        }
+                    }
+                }
+            }
+        })
+        .collect()
+}
+
+/// Ideal (lossless-element) insertion loss in dB at a frequency.
+///
+/// The frequency is mapped to the prototype domain (Ω = f/f_c, f_c/f,
+/// (f/f₀ − f₀/f)/Δ or Δ/(f/f₀ − f₀/f)) and the doubly terminated ladder is
+/// evaluated with ABCD parameters:
+///
+/// IL = −20·log₁₀|2√(R_S/R_L) / (A + B/R_L + C·R_S + D·R_S/R_L)|
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::filter::{self, Approximation, FilterResponse};
+///
+/// let p = filter::prototype(Approximation::Butterworth, 5).unwrap();
+/// let lp = FilterResponse::Lowpass { cutoff: 100e6 };
+/// assert!((filter::insertion_loss_db(&p, lp, 100e6) - 3.0103).abs() < 1e-3);
+/// assert!(filter::insertion_loss_db(&p, lp, 200e6) > 30.0);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `frequency` is not positive.
+#[doc(alias = "attenuation")]
+#[doc(alias = "S21")]
+#[must_use]
+pub fn insertion_loss_db(prototype: &Prototype, response: FilterResponse, frequency: f64) -> f64 {
+    assert!(frequency > 0.0, "frequency must be positive");
+    let omega = match response {
+        FilterResponse::Lowpass { cutoff } => frequency / cutoff,
+        FilterResponse::Highpass { cutoff } => cutoff / frequency,
+        FilterResponse::Bandpass { center, bandwidth } => {
+            (frequency / center - center / frequency) * center / bandwidth
+        }
+        FilterResponse::Bandstop { center, bandwidth } => {
+            bandwidth / center / (frequency / center - center / frequency)
+        }
+    };
+    -10.0 * power_transmission(prototype, omega.abs().min(1e12)).log10()
+}
+
+/// Equivalent noise bandwidth in Hz, referenced to the peak passband gain.
+///
+/// ENBW = f_c·∫₀^∞ |S₂₁(jΩ)|² dΩ for lowpass, and BW·∫₀^∞ |S₂₁(jΩ)|² dΩ for
+/// bandpass (exact for the reactance transformation). Highpass and bandstop
+/// responses pass noise to infinite frequency and return `f64::INFINITY`.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::filter::{self, Approximation, FilterResponse};
+///
+/// // Single-pole RC: ENBW = (π/2)·f_c
+/// let p = filter::prototype(Approximation::Butterworth, 1).unwrap();
+/// let enbw = filter::noise_bandwidth(&p, FilterResponse::Lowpass { cutoff: 1e6 });
+/// assert!((enbw - std::f64::consts::FRAC_PI_2 * 1e6).abs() < 1.0);
+/// ```
+#[doc(alias = "ENBW")]
+#[doc(alias = "equivalent noise bandwidth")]
+#[must_use]
+pub fn noise_bandwidth(prototype: &Prototype, response: FilterResponse) -> f64 {
+    let scale = match response {
+        FilterResponse::Lowpass { cutoff } => cutoff,
+        FilterResponse::Bandpass { bandwidth, .. } => bandwidth,
+        FilterResponse::Highpass { .. } | FilterResponse::Bandstop { .. } => {
+            return f64::INFINITY;
+        }
+    };
+
+    // Ω = tan θ maps [0, ∞) onto [0, π/2); composite Simpson's rule.
+    let steps = 4000;
+    let h = PI / 2.0 / steps as f64;
+    let integrand = |theta: f64| {
+        let omega = theta.tan();
+        power_transmission(prototype, omega) * (1.0 + omega * omega)
+    };
+    let mut sum = integrand(0.0) + integrand(PI / 2.0);
+    for i in 1..steps {
+        let weight = if i % 2 == 1 { 4.0 } else { 2.0 };
+        sum += weight * integrand(i as f64 * h);
+    }
+    scale * sum * h / 3.0
+}
+
+fn band(center: f64, bandwidth: f64) -> (f64, f64) {
+    assert!(center > 0.0, "center frequency must be positive");
+    assert!(bandwidth > 0.0, "bandwidth must be positive");
+    (2.0 * PI * center, bandwidth / center)
+}
+
+/// |S₂₁(jΩ)|² of the normalized ladder.
+fn power_transmission(prototype: &Prototype, omega: f64) -> f64 {
+    let s = Complex::new(0.0, omega);
+    let (mut a, mut b, mut c, mut d) = (Complex::ONE, Complex::ZERO, Complex::ZERO, Complex::ONE);
+    for (k, (&g, &cr)) in prototype.g.iter().zip(&prototype.resonators).enumerate() {
+        if k % 2 == 0 {
+            let y = s.scale(g);
+            a = a + b * y;
+            c = c + d * y;
+        } else {
+            let z = s.scale(g) / (Complex::ONE + (s * s).scale(g * cr));
+            b = a * z + b;
+            d = c * z + d;
+        }
+    }
+    let rl = prototype.load_resistance();
+    let s21 = Complex::from(2.0 / rl.sqrt()) / (a + (b + d).scale(1.0 / rl) + c);
+    s21.norm_sqr()
+}
+
+fn butterworth(n: usize) -> Prototype {
+    let g = (1..=n)
+        .map(|k| 2.0 * ((2 * k - 1) as f64 * PI / (2 * n) as f64).sin())
+        .collect();
+    Prototype {
+        g,
+        resonators: vec![0.0; n],
+        load: 1.0,
+    }
+}
+
+fn chebyshev(n: usize, ripple_db: f64) -> Prototype {
+    let beta = (1.0 / (ripple_db / 17.37).tanh()).ln();
+    let gamma = (beta / (2 * n) as f64).sinh();
+    let a = |k: usize| ((2 * k - 1) as f64 * PI / (2 * n) as f64).sin();
+    let b = |k: usize| gamma * gamma + (k as f64 * PI / n as f64).sin().powi(2);
+
+    let mut g = vec![2.0 * a(1) / gamma];
+    for k in 2..=n {
+        g.push(4.0 * a(k - 1) * a(k) / (b(k - 1) * g[k - 2]));
+    }
+    let load = if n % 2 == 1 {
+        1.0
+    } else {
+        (1.0 / (beta / 4.0).tanh()).powi(2)
+    };
+    Prototype {
+        g,
+        resonators: vec![0.0; n],
+        load,
+    }
+}
+
+fn bessel(n: usize) -> Prototype {
+    // Reverse Bessel polynomial θₙ(s) = (2n − 1)θₙ₋₁(s) + s²θₙ₋₂(s).
+    let mut prev = vec![1.0];
+    let mut d = vec![1.0, 1.0];
+    for k in 2..=n {
+        let next = poly_add(
+            &poly_scale(&d, (2 * k - 1) as f64),
+            &poly_shift(&poly_shift(&prev)),
+        );
+        prev = std::mem::replace(&mut d, next);
+    }
+
+    // F(s)F(−s) = D(s)D(−s) − D(0)², with zeros chosen in the left half-plane.
+    let e = poly_sub(&even_part_product(&d), &[d[0] * d[0]]);
+    let x_roots = complex::roots(&e[1..]);
+    let f = real_poly_from_roots(d[n], x_roots.iter().map(|x| -x.sqrt()));
+    let f = poly_shift(&f);
+
+    let mut p = synthesize(&d, &f, &[]);
+
+    // Unit group delay → 3 dB at Ω = 1.
+    let target = 2.0_f64.log10() * 10.0;
+    let (mut lo, mut hi) = (1e-3_f64, 1e3_f64);
+    for _ in 0..200 {
+        let mid = (lo * hi).sqrt();
+        if -10.0 * power_transmission(&p, mid).log10() < target {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    let w3 = (lo * hi).sqrt();
+    for g in &mut p.g {
+        *g *= w3;
+    }
+    p
+}
+
+fn elliptic(n: usize, ripple_db: f64, stopband_db: f64) -> Option<Prototype> {
+    let ep = (10f64.powf(ripple_db / 10.0) - 1.0).sqrt();
+    let es = (10f64.powf(stopband_db / 10.0) - 1.0).sqrt();
+    let k1 = ep / es;
+    let k1p = (1.0 - k1 * k1).sqrt();
+
+    let u: Vec<f64> = (1..=(n - 1) / 2)
+        .map(|i| (2 * i - 1) as f64 / n as f64)
+        .collect();
+
+    // Degree equation: k' = k₁'ⁿ · Π sn⁴(uᵢK', k₁')
+    let kp = k1p.powi(n as i32) * u.iter().map(|&ui| sn(ui, k1p).powi(4)).product::<f64>();
+    let k = (1.0 - kp * kp).sqrt();
+
+    let zeta: Vec<f64> = u.iter().map(|&ui| cd(ui, k)).collect();
+    let zeros: Vec<f64> = zeta.iter().map(|z| 1.0 / (k * z)).collect();
+    let c0: f64 = zeta
+        .iter()
+        .map(|z| (1.0 - k * k * z * z) / (1.0 - z * z))
+        .product();
+    let c = ep * c0 / zeta.iter().map(|z| k * k * z * z).product::<f64>();
+
+    // In x = s²: D(s)D(−s) = P(x)² − c²·x·Φ(x)²
+    let p_x = zeros
+        .iter()
+        .fold(vec![1.0], |acc, w| poly_mul(&acc, &[w * w, 1.0]));
+    let phi_x = zeta
+        .iter()
+        .fold(vec![1.0], |acc, z| poly_mul(&acc, &[z * z, 1.0]));
+    let e = poly_sub(
+        &poly_mul(&p_x, &p_x),
+        &poly_scale(&poly_shift(&poly_mul(&phi_x, &phi_x)), c * c),
+    );
+    let d = real_poly_from_roots(c, complex::roots(&e).iter().map(|x| -x.sqrt()));
+
+    // F(s) = c·s·Φ(s²)
+    let mut f = vec![0.0; n + 1];
+    for (i, &coefficient) in phi_x.iter().enumerate() {
+        f[2 * i + 1] = c * coefficient;
+    }
+
+    // Pick the extraction order that yields all-positive elements. Every
+    // order leaves a negative end capacitor when the zeros crowd the band
+    // edge, and the response then has no realization in this topology.
+    permutations(&zeros)
+        .iter()
+        .map(|order| synthesize(&d, &f, order))
+        .find(|p| p.g.iter().chain(&p.resonators).all(|&v| v >= 0.0))
+}
+
+/// Ladder synthesis of Y(s) = (D + F) / (D − F), starting with a shunt
+/// capacitor. Finite transmission zeros are removed by zero shifting as
+/// series L‖C tanks; the remainder by continued-fraction expansion at ∞.
+fn synthesize(d: &[f64], f: &[f64], zeros: &[f64]) -> Prototype {
+    let mut num = poly_add(d, f);
+    let mut den = poly_sub(d, f);
+    den.truncate(d.len() - 1);
+
+    let mut g = Vec::new();
+    let mut resonators = Vec::new();
+
+    for &wz in zeros {
+        let jw = Complex::new(0.0, wz);
+        let y = complex::polyval(&num, jw) / complex::polyval(&den, jw);
+        let shunt = y.im / wz;
+        g.push(shunt);
+        resonators.push(0.0);
+
+        let q = divide_quadratic(
+            &poly_sub(&num, &poly_scale(&poly_shift(&den), shunt)),
+            wz * wz,
+        );
+        let residue = (complex::polyval(&den, jw) / (jw * complex::polyval(&q, jw))).re;
+        g.push(residue / (wz * wz));
+        resonators.push(1.0 / residue);
+
+        den = divide_quadratic(
+            &poly_sub(&den, &poly_scale(&poly_shift(&q), residue)),
+            wz * wz,
+        );
+        num = q;
+    }
+
+    loop {
+        if num.len() == 1 && den.len() == 1 {
+            break;
+        }
+        let value = num[num.len() - 1] / den[den.len() - 1];
+        let mut rest = poly_sub(&num, &poly_scale(&poly_shift(&den), value));
+        rest.truncate((den.len() - 1).max(1));
+        g.push(value);
+        resonators.push(0.0);
+        num = std::mem::replace(&mut den, rest);
+    }
+
+    Prototype {
+        g,
+        resonators,
+        load: num[0] / den[0],
+    }
+}
+
+/// sn(u·K, k) by descending Landen transformation.
+fn sn(u: f64, k: f64) -> f64 {
+    landen(k).iter().rev().fold((u * PI / 2.0).sin(), |w, &v| {
+        (1.0 + v) * w / (1.0 + v * w * w)
+    })
+}
+
+/// cd(u·K, k) by descending Landen transformation.
+fn cd(u: f64, k: f64) -> f64 {
+    landen(k).iter().rev().fold((u * PI / 2.0).cos(), |w, &v| {
+        (1.0 + v) * w / (1.0 + v * w * w)
+    })
+}
+
+fn landen(mut k: f64) -> Vec<f64> {
+    let mut v = Vec::new();
+    while k > 1e-15 && v.len() < 32 {
+        k = (k / (1.0 + (1.0 - k * k).sqrt())).powi(2);
+        v.push(k);
+    }
+    v
+}
+
+fn permutations(values: &[f64]) -> Vec<Vec<f64>> {
+    if values.len() <= 1 {
+        return vec![values.to_vec()];
+    }
+    let mut out = Vec::new();
+    for i in 0..values.len() {
+        let mut rest = values.to_vec();
+        let first = rest.remove(i);
+        for mut tail in permutations(&rest) {
+            tail.insert(0, first);
+            out.push(tail);
+        }
+    }
+    out
+}
+
+/// Coefficients (in x = s²) of D(s)·D(−s).
+fn even_part_product(d: &[f64]) -> Vec<f64> {
+    let mirrored: Vec<f64> = d
+        .iter()
+        .enumerate()
+        .map(|(i, &c)| if i % 2 == 0 { c } else { -c })
+        .collect();
+    poly_mul(d, &mirrored).into_iter().step_by(2).collect()
+}
+
+/// lead · Π(s − rootᵢ) for roots in conjugate pairs.
+fn real_poly_from_roots(lead: f64, roots: impl Iterator<Item = Complex>) -> Vec<f64> {
+    let mut p = vec![Complex::from(lead)];
+    for r in roots {
+        let mut next = vec![Complex::ZERO; p.len() + 1];
+        for (i, &c) in p.iter().enumerate() {
+            next[i + 1] = next[i + 1] + c;
+            next[i] = next[i] - c * r;
+        }
+        p = next;
+    }
+    p.into_iter().map(|c| c.re).collect()
+}
+
+/// Quotient of p(s) / (s² + w2), discarding the (ideally zero) remainder.
+fn divide_quadratic(p: &[f64], w2: f64) -> Vec<f64> {
+    let m = p.len() - 1;
+    let mut q = vec![0.0; m - 1];
+    for i in (0..m - 1).rev() {
+        q[i] = p[i + 2] - if i + 2 < m - 1 { w2 * q[i + 2] } else { 0.0 };
+    }
+    q
+}
+
+fn poly_mul(a: &[f64], b: &[f64]) -> Vec<f64> {
+    let mut out = vec![0.0; a.len() + b.len() - 1];
+    for (i, &x) in a.iter().enumerate() {
+        for (j, &y) in b.iter().enumerate() {
+            out[i + j] += x * y;
+        }
+    }
+    out
+}
+
+fn poly_add(a: &[f64], b: &[f64]) -> Vec<f64> {
+    let mut out = vec![0.0; a.len().max(b.len())];
+    for (i, o) in out.iter_mut().enumerate() {
+        *o = a.get(i).unwrap_or(&0.0) + b.get(i).unwrap_or(&0.0);
+    }
+    out
+}
+
+fn poly_sub(a: &[f64], b: &[f64]) -> Vec<f64> {
+    poly_add(a, &poly_scale(b, -1.0))
+}
+
+fn poly_scale(a: &[f64], k: f64) -> Vec<f64> {
+    a.iter().map(|c| c * k).collect()
+}
+
+/// Multiply by s.
+fn poly_shift(a: &[f64]) -> Vec<f64> {
+    let mut out = vec![0.0];
+    out.extend_from_slice(a);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Approximation, Element, FilterResponse};
+
+    fn close(a: f64, b: f64, tol: f64) -> bool {
+        (a - b).abs() < tol
+    }
+
+    #[test]
+    fn butterworth_third_order() {
+        let p = super::prototype(Approximation::Butterworth, 3).unwrap();
+        assert!(close(p.g[0], 1.0, 1e-12));
+        assert!(close(p.g[1], 2.0, 1e-12));
+        assert!(close(p.g[2], 1.0, 1e-12));
+        assert_eq!(p.load, 1.0);
+    }
+
+    #[test]
+    fn chebyshev_even_order_load() {
+        // Matthaei/Pozar table, 0.5 dB ripple, n = 4
+        let p = super::prototype(Approximation::Chebyshev { ripple_db: 0.5 }, 4).unwrap();
+        let expected = [1.6703, 1.1926, 2.3661, 0.8419];
+        for (g, e) in p.g.iter().zip(expected) {
+            assert!(close(*g, e, 1e-3), "{g} vs {e}");
+        }
+        assert!(close(p.load, 1.9841, 1e-3));
+    }
+
+    #[test]
+    fn chebyshev_ripple_at_dc_for_even_order() {
+        let p = super::prototype(Approximation::Chebyshev { ripple_db: 0.5 }, 4).unwrap();
+        let lp = FilterResponse::Lowpass { cutoff: 1.0 };
+        assert!(close(super::insertion_loss_db(&p, lp, 1e-6), 0.5, 1e-3));
+        assert!(close(super::insertion_loss_db(&p, lp, 1.0), 0.5, 1e-3));
+    }
+
+    #[test]
+    fn bessel_matches_delay_normalized_table() {
+        // Ratios are unaffected by the 3 dB renormalization.
+        let p = super::prototype(Approximation::Bessel, 3).unwrap();
+        assert!(close(p.g[0] / p.g[1], 1.2550 / 0.5528, 1e-3));
+        assert!(close(p.g[1] / p.g[2], 0.5528 / 0.1922, 1e-2));
+        assert!(close(p.load, 1.0, 1e-9));
+
+        let p = super::prototype(Approximation::Bessel, 5).unwrap();
+        let table = [0.9303, 0.4577, 0.3312, 0.2090, 0.0718];
+        for k in 1..5 {
+            assert!(close(p.g[k] / p.g[0], table[k] / table[0], 1e-3));
+        }
+    }
+
+    #[test]
+    fn bessel_is_3db_down_at_cutoff() {
+        let p = super::prototype(Approximation::Bessel, 4).unwrap();
+        let lp = FilterResponse::Lowpass { cutoff: 1.0 };
+        assert!(close(super::insertion_loss_db(&p, lp, 1.0), 3.0103, 1e-3));
+    }
+
+    #[test]
+    fn elliptic_meets_ripple_and_stopband() {
+        for n in [3, 5, 7] {
+            let p = super::prototype(
+                Approximation::Elliptic {
+                    ripple_db: 0.1,
+                    stopband_db: 50.0,
+                },
+                n,
+            )
+            .unwrap();
+            assert!(p.g.iter().all(|&v| v > 0.0));
+            assert!(p.resonators.iter().all(|&v| v >= 0.0));
+            assert!(close(p.load, 1.0, 1e-6));
+
+            let lp = FilterResponse::Lowpass { cutoff: 1.0 };
+            let passband_max = (1..=100)
+                .map(|i| super::insertion_loss_db(&p, lp, i as f64 / 100.0))
+                .fold(0.0, f64::max);
+            assert!(close(passband_max, 0.1, 1e-3), "n={n}: {passband_max}");
+
+            // Stopband floor beyond the lowest transmission zero
+            let lowest_zero = (1..n)
+                .step_by(2)
+                .map(|k| 1.0 / (p.g[k] * p.resonators[k]).sqrt())
+                .fold(f64::INFINITY, f64::min);
+            let stopband_min = (0..4000)
+                .map(|i| lowest_zero * (1.0 + i as f64 * 0.005))
+                .map(|f| super::insertion_loss_db(&p, lp, f))
+                .fold(f64::INFINITY, f64::min);
+            assert!(
+                stopband_min > 49.99 && stopband_min < 50.1,
+                "n={n}: {stopband_min}"
+            );
+        }
+    }
+
+    #[test]
+    fn elliptic_sharper_than_chebyshev() {
+        let e = super::prototype(
+            Approximation::Elliptic {
+                ripple_db: 0.5,
+                stopband_db: 40.0,
+            },
+            5,
+        )
+        .unwrap();
+        let c = super::prototype(Approximation::Chebyshev { ripple_db: 0.5 }, 5).unwrap();
+        let lp = FilterResponse::Lowpass { cutoff: 1.0 };
+        assert!(super::insertion_loss_db(&e, lp, 1.3) > super::insertion_loss_db(&c, lp, 1.3));
+    }
+
+    #[test]
+    fn highpass_mirrors_lowpass() {
+        let p = super::prototype(Approximation::Butterworth, 3).unwrap();
+        let hp = FilterResponse::Highpass { cutoff: 1e9 };
+        let lp = FilterResponse::Lowpass { cutoff: 1e9 };
+        let a = super::insertion_loss_db(&p, hp, 0.5e9);
+        let b = super::insertion_loss_db(&p, lp, 2e9);
+        assert!(close(a, b, 1e-9));
+    }
+
+    #[test]
+    fn bandpass_and_bandstop_responses() {
+        let p = super::prototype(Approximation::Chebyshev { ripple_db: 0.1 }, 3).unwrap();
+        let bp = FilterResponse::Bandpass {
+            center: 1e9,
+            bandwidth: 100e6,
+        };
+        assert!(super::insertion_loss_db(&p, bp, 1e9) < 1e-9);
+        assert!(super::insertion_loss_db(&p, bp, 1.2e9) > 20.0);
+
+        let bs = FilterResponse::Bandstop {
+            center: 1e9,
+            bandwidth: 100e6,
+        };
+        assert!(super::insertion_loss_db(&p, bs, 1e9) > 100.0);
+        assert!(super::insertion_loss_db(&p, bs, 2e9) < 0.1);
+    }
+
+    #[test]
+    fn bandpass_elements_resonate_at_center() {
+        let p = super::prototype(Approximation::Butterworth, 2).unwrap();
+        let elements = super::transform(
+            &p,
+            FilterResponse::Bandpass {
+                center: 100e6,
+                bandwidth: 10e6,
+            },
+            50.0,
+        );
+        for e in elements {
+            let (l, c) = match e {
+                Element::ShuntTank {
+                    inductance,
+                    capacitance,
+                }
+                | Element::SeriesLc {
+                    inductance,
+                    capacitance,
+                } => (inductance, capacitance),
+                _ => unreachable!(),
+            };
+            let f = 1.0 / (2.0 * std::f64::consts::PI * (l * c).sqrt());
+            assert!(close(f, 100e6, 1.0));
+        }
+    }
+
+    #[test]
+    fn lowpass_elliptic_uses_tanks() {
+        let p = super::prototype(
+            Approximation::Elliptic {
+                ripple_db: 0.1,
+                stopband_db: 40.0,
+            },
+            3,
+        )
+        .unwrap();
+        let elements = super::transform(&p, FilterResponse::Lowpass { cutoff: 1e9 }, 50.0);
+        assert!(matches!(elements[1], Element::SeriesTank { .. }));
+    }
+
+    #[test]
+    fn elliptic_bandpass_and_bandstop_map_series_tanks() {
+        let p = super::prototype(
+            Approximation::Elliptic {
+                ripple_db: 0.1,
+                stopband_db: 40.0,
+            },
+            5,
+        )
+        .unwrap();
+        let (center, bandwidth, z0) = (1e9, 100e6, 50.0);
+        let f = 1.03e9;
+        let w = 2.0 * std::f64::consts::PI * f;
+        let x = f / center - center / f;
+        // Prototype series arm jΩg / (1 − Ω²gc), as a reactance
+        let arm = |omega: f64| omega * p.g[1] / (1.0 - omega * omega * p.g[1] * p.resonators[1]);
+
+        for (response, omega) in [
+            (
+                FilterResponse::Bandpass { center, bandwidth },
+                x * center / bandwidth,
+            ),
+            (
+                FilterResponse::Bandstop { center, bandwidth },
+                -bandwidth / center / x,
+            ),
+        ] {
+            let Element::SeriesResonatorPair {
+                tank_inductance,
+                tank_capacitance,
+                branch_inductance,
+                branch_capacitance,
+            } = super::transform(&p, response, z0)[1]
+            else {
+                panic!("elliptic series tank should map to a resonator pair");
+            };
+            let tank = w * tank_capacitance - 1.0 / (w * tank_inductance);
+            let branch = -1.0 / (w * branch_inductance - 1.0 / (w * branch_capacitance));
+            let reactance = -1.0 / (tank + branch);
+            let expected = z0 * arm(omega);
+            assert!(
+                close(reactance, expected, 1e-9 * expected.abs()),
+                "{response:?}: {reactance} vs {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn elliptic_without_positive_ladder_is_none() {
+        let shallow = |stopband_db| Approximation::Elliptic {
+            ripple_db: 0.1,
+            stopband_db,
+        };
+        assert!(super::prototype(shallow(10.0), 5).is_none());
+        assert!(super::prototype(shallow(20.0), 5).is_some());
+    }
+
+    #[test]
+    #[should_panic(expected = "elliptic prototypes require an odd order from 3 to 9")]
+    fn elliptic_even_order_panics() {
+        let _ = super::prototype(
+            Approximation::Elliptic {
+                ripple_db: 0.1,
+                stopband_db: 40.0,
+            },
+            4,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn butterworth_noise_bandwidth() {
+        // ENBW/f_c = (π/2n) / sin(π/2n)
+        for n in 1..=5 {
+            let p = super::prototype(Approximation::Butterworth, n).unwrap();
+            let x = std::f64::consts::PI / (2 * n) as f64;
+            let enbw = super::noise_bandwidth(&p, FilterResponse::Lowpass { cutoff: 1.0 });
+            assert!(close(enbw, x / x.sin(), 1e-6), "n={n}: {enbw}");
+        }
+    }
+
+    #[test]
+    fn bandpass_noise_bandwidth_scales_with_bandwidth() {
+        let p = super::prototype(Approximation::Butterworth, 2).unwrap();
+        let enbw = super::noise_bandwidth(
+            &p,
+            FilterResponse::Bandpass {
+                center: 1e9,
+                bandwidth: 1e6,
+            },
+        );
+        assert!(close(enbw, 1.1107e6, 100.0));
+        let hp = super::noise_bandwidth(&p, FilterResponse::Highpass { cutoff: 1e6 });
+        assert!(hp.is_infinite());
+    }
+}
@@ -0,0 +1,198 @@
+use crate::power::{db_to_linear, dbm_to_milliwatts, milliwatts_to_dbm};
+
+/// Convert input IP2 to output IP2 (dBm).
+///
+/// OIP2 = IIP2 + G
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::intercept::iip2_to_oip2;
+/// assert_eq!(iip2_to_oip2(45.0, 12.0), 57.0);
+/// ```
+#[doc(alias = "OIP2")]
+#[must_use]
+pub fn iip2_to_oip2(iip2_dbm: f64, gain_db: f64) -> f64 {
+    iip2_dbm + gain_db
+}
+
+/// Convert output IP2 to input IP2 (dBm).
+///
+/// IIP2 = OIP2 − G
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::intercept::oip2_to_iip2;
+/// assert_eq!(oip2_to_iip2(57.0, 12.0), 45.0);
+/// ```
+#[doc(alias = "IIP2")]
+#[must_use]
+pub fn oip2_to_iip2(oip2_dbm: f64, gain_db: f64) -> f64 {
+    oip2_dbm - gain_db
+}
+
+/// Input-referred IP2 (dBm) of a cascade of `(iip2_dbm, gain_db)` stages,
+/// assuming the IM2 products add coherently (worst case).
+///
+/// 1/√IIP2 = Σ √(G₁⋯Gᵢ₋₁ / IIP2ᵢ)
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::intercept::cascade_iip2;
+/// // LNA (IIP2 40 dBm, 15 dB) → mixer (IIP2 50 dBm)
+/// let iip2 = cascade_iip2(&[(40.0, 15.0), (50.0, -7.0)]);
+/// assert!((iip2 - 31.13).abs() < 0.01);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `stages` is empty.
+#[must_use]
+pub fn cascade_iip2(stages: &[(f64, f64)]) -> f64 {
+    assert!(!stages.is_empty(), "stages must not be empty");
+    let mut gain = 1.0;
+    let mut sum = 0.0;
+    for &(iip2_dbm, gain_db) in stages {
+        sum += (gain / dbm_to_milliwatts(iip2_dbm)).sqrt();
+        gain *= db_to_linear(gain_db);
+    }
+    milliwatts_to_dbm(1.0 / (sum * sum))
+}
+
+/// Input-referred IM2 product level (dBm) for two tones of equal input
+/// power (dBm each).
+///
+/// P_IM2 = 2·P_in − IIP2
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::intercept::im2_input_dbm;
+/// assert_eq!(im2_input_dbm(-30.0, 40.0), -100.0);
+/// ```
+#[doc(alias = "IMD2")]
+#[must_use]
+pub fn im2_input_dbm(input_dbm: f64, iip2_dbm: f64) -> f64 {
+    2.0 * input_dbm - iip2_dbm
+}
+
+/// Output IM2 product level (dBm) for two tones of equal input power.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::intercept::im2_output_dbm;
+/// assert_eq!(im2_output_dbm(-30.0, 40.0, 20.0), -80.0);
+/// ```
+#[must_use]
+pub fn im2_output_dbm(input_dbm: f64, iip2_dbm: f64, gain_db: f64) -> f64 {
+    im2_input_dbm(input_dbm, iip2_dbm) + gain_db
+}
+
+/// Input IP2 (dBm) from a two-tone measurement: the per-tone input power
+/// and the IM2 level below each output tone (dBc, positive).
+///
+/// IIP2 = P_in + ΔIM2
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::intercept::iip2_from_measurement;
+/// assert_eq!(iip2_from_measurement(-30.0, 70.0), 40.0);
+/// ```
+#[must_use]
+pub fn iip2_from_measurement(input_dbm: f64, im2_below_carrier_db: f64) -> f64 {
+    input_dbm + im2_below_carrier_db
+}
+
+/// Level (dBc) of the nth harmonic relative to the fundamental, from the
+/// input-referred nth-harmonic intercept point.
+///
+/// HDn = −(n − 1)·(HIPn − P_in)
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::intercept::harmonic_dbc;
+/// // HIP2 of +30 dBm at −10 dBm drive → −40 dBc second harmonic
+/// assert_eq!(harmonic_dbc(-10.0, 30.0, 2), -40.0);
+/// // HIP3 of +20 dBm at −10 dBm drive → −60 dBc third harmonic
+/// assert_eq!(harmonic_dbc(-10.0, 20.0, 3), -60.0);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `order` is less than 2.
+#[doc(alias = "HD2")]
+#[doc(alias = "HD3")]
+#[must_use]
+pub fn harmonic_dbc(input_dbm: f64, intercept_dbm: f64, order: u32) -> f64 {
+    assert!(order >= 2, "harmonic order must be at least 2");
+    -((order - 1) as f64) * (intercept_dbm - input_dbm)
+}
+
+/// Input-referred nth-harmonic intercept (dBm) from a measured harmonic
+/// level (dBc) at an input power.
+///
+/// HIPn = P_in − HDn / (n − 1)
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::intercept::harmonic_intercept_dbm;
+/// assert_eq!(harmonic_intercept_dbm(-10.0, -60.0, 3), 20.0);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `order` is less than 2.
+#[must_use]
+pub fn harmonic_intercept_dbm(input_dbm: f64, harmonic_dbc: f64, order: u32) -> f64 {
+    assert!(order >= 2, "harmonic order must be at least 2");
+    input_dbm - harmonic_dbc / (order - 1) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn single_stage_cascade_is_unchanged() {
+        assert!((super::cascade_iip2(&[(42.0, 10.0)]) - 42.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn gain_ahead_degrades_later_stage() {
+        // A perfectly linear first stage (infinite IIP2) with 20 dB gain
+        // refers a 50 dBm IIP2 to 30 dBm.
+        let iip2 = super::cascade_iip2(&[(f64::INFINITY, 20.0), (50.0, 0.0)]);
+        assert!((iip2 - 30.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn im2_meets_fundamental_at_intercept() {
+        let iip2 = 35.0;
+        assert!((super::im2_input_dbm(iip2, iip2) - iip2).abs() < 1e-12);
+    }
+
+    #[test]
+    fn measurement_round_trip() {
+        let pin = -25.0;
+        let im2 = super::im2_input_dbm(pin, 48.0);
+        assert_eq!(super::iip2_from_measurement(pin, pin - im2), 48.0);
+    }
+
+    #[test]
+    fn harmonic_round_trip() {
+        for order in 2..=5 {
+            let dbc = super::harmonic_dbc(-5.0, 25.0, order);
+            assert!((super::harmonic_intercept_dbm(-5.0, dbc, order) - 25.0).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "harmonic order must be at least 2")]
+    fn rejects_fundamental_as_harmonic() {
+        let _ = super::harmonic_dbc(0.0, 20.0, 1);
+    }
+}
@@ -1,13 +1,61 @@
 #![warn(missing_docs)]
 //! RF engineering unit conversions for power, frequency, noise, and compression point analysis.
 
+/// ADC/DAC conversions: ENOB, SINAD, full-scale power, dBFS, noise spectral density, and noise figure.
+pub mod adc;
+/// Power amplifier behavioral models (Rapp, Saleh, Ghorbani, cubic): AM/AM, AM/PM, compression points, and fitting.
+pub mod amplifier;
+/// Resistive Pi, T, and bridged-T attenuator pad design.
+pub mod attenuator;
+/// IEEE, ITU, NATO, and satellite frequency band designations.
+pub mod band;
+/// Equivalent noise bandwidth of analog filters, pulse-shaping filters, RBW filters, and FFT windows.
+pub mod bandwidth;
+/// Theoretical bit and symbol error rates in AWGN, erfc and Q-function.
+pub mod ber;
+/// Shannon capacity, spectral efficiency bounds, and Shannon-limit gap.
+pub mod capacity;
+/// LTE EARFCN, 5G NR-ARFCN and GSCN, Wi-Fi, and Bluetooth channel to frequency conversions.
+pub mod channel;
+/// Complex numbers for impedances, admittances, and reflection coefficients.
+pub mod complex;
+/// Compression analysis of measured power sweeps: small-signal gain, P1dB/P2dB/P3dB, Psat, and gain expansion.
+pub mod compression;
 /// Physical constants used by the conversion routines.
 pub mod constants;
+/// Doppler shift, two-way radar Doppler, LEO pass Doppler, and Doppler rate, with carrier frequencies in Hz.
+pub mod doppler;
+/// Amplifier drain efficiency, PAE, DC and dissipated power, junction temperature, and class-A/B efficiency at backoff.
+pub mod efficiency;
+/// EVM, MER, and SNR conversions with an RSS impairment budget.
+pub mod evm;
+/// Lumped-element filter prototypes, frequency transformations, insertion loss, and noise bandwidth.
+pub mod filter;
 /// Frequency and wavelength conversions.
 pub mod frequency;
+/// Second-order intercept (IIP2/OIP2), cascaded IP2, IM2 levels, and harmonic intercept points.
+pub mod intercept;
+/// Receiver gain line-up analysis against an ADC: noise margin, headroom, and the sensitivity/headroom trade.
+pub mod lineup;
+/// Lumped L, Pi, and T impedance matching network synthesis and quarter-wave transformers.
+pub mod matching;
 /// Noise figure, noise factor, noise temperature, and thermal noise conversions.
 pub mod noise;
 /// P1dB compression point conversion helpers.
 pub mod p1db;
+/// Envelope peak-to-average power ratio (CW is 0 dB), crest factor, theoretical PAPR of common signals, and amplifier backoff.
+pub mod papr;
+/// Phase noise interpolation and integration to RMS phase error, jitter, and residual FM.
+pub mod phase_noise;
 /// Power conversions including watts, dBm, dBW, and linear ratios.
 pub mod power;
+/// Smith chart coordinate conversions, grid and noise circles, and SVG/ASCII rendering.
+pub mod smith;
+/// SNR, C/N, C/N₀, Eb/N₀, and Es/N₀ link-quality conversions.
+pub mod snr;
+/// Mixing product and harmonic spur search for frequency planning, with frequencies in Hz.
+pub mod spur;
+/// Mixer and superheterodyne frequency planning: LO, IF, image, half-IF, and multi-conversion chains.
+pub mod superhet;
+/// Rectangular and circular waveguide cutoff, dispersion, and loss calculations.
+pub mod waveguide;
@@ -0,0 +1,330 @@
+use crate::adc::noise_spectral_density_dbm_per_hz;
+use crate::constants::T0;
+use crate::noise::{
+    cascade_noise_figure, noise_density_dbm_per_hz, noise_figure_from_noise_factor,
+    noise_temperature_from_noise_figure,
+};
+use crate::p1db::cascade_output_p1db;
+use crate::power::{db_to_linear, linear_to_db};
+
+/// One analog stage of a receiver line-up.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stage {
+    /// Noise figure in dB.
+    pub noise_figure_db: f64,
+    /// Gain in dB.
+    pub gain_db: f64,
+    /// Output 1 dB compression point in dBm.
+    pub output_p1db_dbm: f64,
+}
+
+/// The ADC terminating a line-up.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Converter {
+    /// Full-scale sine power in dBm.
+    pub full_scale_dbm: f64,
+    /// Input-referred noise spectral density in dBm/Hz.
+    pub noise_spectral_density_dbm_per_hz: f64,
+}
+
+impl Converter {
+    /// Describe a converter from its datasheet SNR across the first Nyquist
+    /// zone.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfconversions::lineup::Converter;
+    /// let adc = Converter::from_snr(4.0, 70.0, 1e9);
+    /// assert!((adc.noise_spectral_density_dbm_per_hz - (-152.99)).abs() < 0.01);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `sample_rate` is not positive.
+    #[must_use]
+    pub fn from_snr(full_scale_dbm: f64, snr_db: f64, sample_rate: f64) -> Self {
+        Self {
+            full_scale_dbm,
+            noise_spectral_density_dbm_per_hz: noise_spectral_density_dbm_per_hz(
+                snr_db,
+                sample_rate,
+                full_scale_dbm,
+            ),
+        }
+    }
+}
+
+/// Noise and headroom analysis of a line-up driving an ADC.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Lineup {
+    /// Number of analog stages analysed.
+    pub stages: usize,
+    /// Total analog gain in dB.
+    pub gain_db: f64,
+    /// Cascaded analog noise figure in dB.
+    pub noise_figure_db: f64,
+    /// Cascaded analog output P1dB in dBm.
+    pub output_p1db_dbm: f64,
+    /// Analog output noise density (antenna plus chain) above the ADC noise
+    /// density, in dB.
+    pub noise_margin_db: f64,
+    /// Noise figure in dB including the ADC as the last stage.
+    pub system_noise_figure_db: f64,
+    /// Total input-referred noise density in dBm/Hz, antenna included.
+    pub input_noise_density_dbm_per_hz: f64,
+    /// Rise in input noise density caused by the ADC, in dB.
+    pub sensitivity_degradation_db: f64,
+    /// ADC full scale minus the output at the maximum input, in dB.
+    pub adc_headroom_db: f64,
+    /// Output P1dB minus the output at the maximum input, in dB.
+    pub compression_headroom_db: f64,
+}
+
+impl Lineup {
+    /// The smaller of the ADC and compression headroom (dB).
+    #[must_use]
+    pub fn headroom_db(&self) -> f64 {
+        self.adc_headroom_db.min(self.compression_headroom_db)
+    }
+}
+
+/// Analog gain (dB) needed for the chain's output noise density to exceed
+/// the ADC noise density by `margin_db`.
+///
+/// G = NSD + margin − 10·log₁₀(k·(T_ant + T_e)·1000)
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::lineup::required_gain_db;
+/// // 290 K antenna, 3 dB NF chain, ADC at −153 dBm/Hz, 10 dB margin
+/// let g = required_gain_db(290.0, 3.0, -153.0, 10.0);
+/// assert!((g - 28.0).abs() < 0.05);
+/// ```
+///
+/// # Panics
+///
+/// Panics if the antenna temperature is negative.
+#[must_use]
+pub fn required_gain_db(
+    antenna_temperature: f64,
+    noise_figure_db: f64,
+    adc_noise_density_dbm_per_hz: f64,
+    margin_db: f64,
+) -> f64 {
+    adc_noise_density_dbm_per_hz + margin_db
+        - input_noise_density(antenna_temperature, noise_figure_db)
+}
+
+/// Analyse a line-up of stages driving an ADC with a maximum expected
+/// input power (dBm).
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::lineup::{analyze, Converter, Stage};
+/// let stages = [
+///     Stage { noise_figure_db: 1.0, gain_db: 20.0, output_p1db_dbm: 15.0 },
+///     Stage { noise_figure_db: 8.0, gain_db: 15.0, output_p1db_dbm: 18.0 },
+/// ];
+/// let adc = Converter::from_snr(4.0, 70.0, 1e9);
+/// let l = analyze(290.0, &stages, adc, -45.0);
+/// assert!((l.gain_db - 35.0).abs() < 1e-12);
+/// assert!(l.noise_margin_db > 10.0);
+/// assert!(l.headroom_db() > 10.0);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `stages` is empty or the antenna temperature is negative.
+#[must_use]
+pub fn analyze(
+    antenna_temperature: f64,
+    stages: &[Stage],
+    converter: Converter,
+    max_input_dbm: f64,
+) -> Lineup {
+    assert!(!stages.is_empty(), "stages must not be empty");
+
+    let cascade: Vec<(f64, f64)> = stages
+        .iter()
+        .map(|s| (s.noise_figure_db, s.gain_db))
+        .collect();
+    let gain_db: f64 = stages.iter().map(|s| s.gain_db).sum();
+    let noise_figure_db = cascade_noise_figure(&cascade);
+    let output_p1db_dbm = stages[1..].iter().fold(stages[0].output_p1db_dbm, |p, s| {
+        cascade_output_p1db(p, s.output_p1db_dbm, s.gain_db)
+    });
+
+    let analog = input_noise_density(antenna_temperature, noise_figure_db);
+    let adc_referred = converter.noise_spectral_density_dbm_per_hz - gain_db;
+    let total = linear_to_db(db_to_linear(analog) + db_to_linear(adc_referred));
+
+    // The ADC noise density is all added noise: F = 1 + NSD / kT₀.
+    let adc_noise_factor = 1.0
+        + db_to_linear(converter.noise_spectral_density_dbm_per_hz - noise_density_dbm_per_hz(T0));
+    let mut with_adc = cascade;
+    with_adc.push((noise_figure_from_noise_factor(adc_noise_factor), 0.0));
+
+    let max_output_dbm = max_input_dbm + gain_db;
+    Lineup {
+        stages: stages.len(),
+        gain_db,
+        noise_figure_db,
+        output_p1db_dbm,
+        noise_margin_db: analog - adc_referred,
+        system_noise_figure_db: cascade_noise_figure(&with_adc),
+        input_noise_density_dbm_per_hz: total,
+        sensitivity_degradation_db: total - analog,
+        adc_headroom_db: converter.full_scale_dbm - max_output_dbm,
+        compression_headroom_db: output_p1db_dbm - max_output_dbm,
+    }
+}
+
+/// Sensitivity versus headroom trade: the analysis of each leading subset
+/// of the candidate stages, from the first stage alone to the full chain.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::lineup::{trade, Converter, Stage};
+/// let lna = Stage { noise_figure_db: 1.0, gain_db: 20.0, output_p1db_dbm: 15.0 };
+/// let adc = Converter::from_snr(4.0, 70.0, 1e9);
+/// let rows = trade(290.0, &[lna, lna, lna], adc, -45.0);
+/// // Each stage buys noise margin and costs headroom
+/// assert!(rows[1].noise_margin_db > rows[0].noise_margin_db);
+/// assert!(rows[1].headroom_db() < rows[0].headroom_db());
+/// ```
+///
+/// # Panics
+///
+/// Panics if the antenna temperature is negative.
+#[must_use]
+pub fn trade(
+    antenna_temperature: f64,
+    candidates: &[Stage],
+    converter: Converter,
+    max_input_dbm: f64,
+) -> Vec<Lineup> {
+    (1..=candidates.len())
+        .map(|n| {
+            analyze(
+                antenna_temperature,
+                &candidates[..n],
+                converter,
+                max_input_dbm,
+            )
+        })
+        .collect()
+}
+
+/// Shortest leading subset of the candidate stages whose noise dominates
+/// the ADC by `margin_db` while the maximum input stays below both the ADC
+/// full scale and output P1dB. Returns `None` if no subset qualifies.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::lineup::{optimize, Converter, Stage};
+/// let lna = Stage { noise_figure_db: 1.0, gain_db: 20.0, output_p1db_dbm: 15.0 };
+/// let adc = Converter::from_snr(4.0, 70.0, 1e9);
+/// let best = optimize(290.0, &[lna, lna, lna], adc, -60.0, 10.0).unwrap();
+/// assert_eq!(best.stages, 2);
+/// ```
+///
+/// # Panics
+///
+/// Panics if the antenna temperature is negative.
+#[must_use]
+pub fn optimize(
+    antenna_temperature: f64,
+    candidates: &[Stage],
+    converter: Converter,
+    max_input_dbm: f64,
+    margin_db: f64,
+) -> Option<Lineup> {
+    trade(antenna_temperature, candidates, converter, max_input_dbm)
+        .into_iter()
+        .find(|l| l.noise_margin_db >= margin_db && l.headroom_db() >= 0.0)
+}
+
+/// Input-referred noise density (dBm/Hz) of an antenna and analog chain.
+fn input_noise_density(antenna_temperature: f64, noise_figure_db: f64) -> f64 {
+    assert!(
+        antenna_temperature >= 0.0,
+        "antenna temperature must not be negative"
+    );
+    noise_density_dbm_per_hz(
+        antenna_temperature + noise_temperature_from_noise_figure(noise_figure_db),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Converter, Stage};
+
+    const LNA: Stage = Stage {
+        noise_figure_db: 1.0,
+        gain_db: 20.0,
+        output_p1db_dbm: 15.0,
+    };
+
+    fn adc() -> Converter {
+        Converter {
+            full_scale_dbm: 4.0,
+            noise_spectral_density_dbm_per_hz: -153.0,
+        }
+    }
+
+    #[test]
+    fn required_gain_meets_margin_exactly() {
+        let g = super::required_gain_db(150.0, 1.0, -153.0, 10.0);
+        let stage = Stage { gain_db: g, ..LNA };
+        let l = super::analyze(150.0, &[stage], adc(), -80.0);
+        assert!((l.noise_margin_db - 10.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn degradation_at_10db_margin() {
+        // 10·log₁₀(1 + 10^(−1)) = 0.41 dB
+        let g = super::required_gain_db(290.0, 1.0, -153.0, 10.0);
+        let l = super::analyze(290.0, &[Stage { gain_db: g, ..LNA }], adc(), -80.0);
+        assert!((l.sensitivity_degradation_db - 0.4139).abs() < 1e-4);
+    }
+
+    #[test]
+    fn system_noise_figure_includes_adc() {
+        let l = super::analyze(290.0, &[LNA], adc(), -80.0);
+        assert!(l.system_noise_figure_db > l.noise_figure_db);
+        // With T_ant = T0 the system NF and input noise density agree.
+        let kt0 = crate::noise::noise_density_dbm_per_hz(290.0);
+        assert!((l.input_noise_density_dbm_per_hz - kt0 - l.system_noise_figure_db).abs() < 1e-9);
+    }
+
+    #[test]
+    fn headroom_tracks_compression() {
+        let l = super::analyze(290.0, &[LNA], adc(), -10.0);
+        assert!((l.compression_headroom_db - 5.0).abs() < 1e-12);
+        assert!((l.adc_headroom_db - (-6.0)).abs() < 1e-12);
+        assert!((l.headroom_db() - (-6.0)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn identical_stages_lose_output_p1db() {
+        // Equal-gain stage after a 15 dBm stage: 35 dBm in parallel with 15 dBm
+        let l = super::analyze(290.0, &[LNA, LNA], adc(), -80.0);
+        assert!((l.output_p1db_dbm - 14.957).abs() < 1e-3);
+    }
+
+    #[test]
+    fn optimize_returns_none_when_headroom_runs_out() {
+        assert!(super::optimize(290.0, &[LNA, LNA, LNA], adc(), -20.0, 10.0).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "stages must not be empty")]
+    fn rejects_empty_lineup() {
+        let _ = super::analyze(290.0, &[], adc(), -50.0);
+    }
+}
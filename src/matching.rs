@@ -0,0 +1,539 @@
+use std::f64::consts::PI;
+
+use crate::complex::Complex;
+
+/// A lumped reactive component value.
+///
+/// A series inductor of 0 H is a short and a shunt capacitor of 0 F is an
+/// open, so degenerate match solutions are still representable.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Component {
+    /// Inductance in henries.
+    Inductor(f64),
+    /// Capacitance in farads.
+    Capacitor(f64),
+}
+
+/// Which side of an L-section the shunt element sits on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LTopology {
+    /// Shunt element across the load, series element toward the source.
+    ShuntAtLoad,
+    /// Shunt element across the source, series element toward the load.
+    ShuntAtSource,
+}
+
+/// Frequency response of a Pi or T match.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatchResponse {
+    /// Series inductors, shunt capacitors.
+    Lowpass,
+    /// Series capacitors, shunt inductors.
+    Highpass,
+}
+
+/// One L-section matching solution.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LMatch {
+    /// Placement of the shunt element.
+    pub topology: LTopology,
+    /// Series element.
+    pub series: Component,
+    /// Shunt element.
+    pub shunt: Component,
+    /// Node Q of the network.
+    pub q: f64,
+    /// Approximate 3 dB bandwidth in Hz, f₀ / Q.
+    pub bandwidth: f64,
+}
+
+/// A Pi matching network, listed from source to load.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PiMatch {
+    /// Shunt element across the source.
+    pub shunt_source: Component,
+    /// Series element between the two shunt elements.
+    pub series: Component,
+    /// Shunt element across the load.
+    pub shunt_load: Component,
+    /// Design (loaded) Q.
+    pub q: f64,
+    /// Approximate 3 dB bandwidth in Hz, f₀ / Q.
+    pub bandwidth: f64,
+}
+
+/// A T matching network, listed from source to load.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TMatch {
+    /// Series element at the source.
+    pub series_source: Component,
+    /// Shunt element at the center node.
+    pub shunt: Component,
+    /// Series element at the load.
+    pub series_load: Component,
+    /// Design (loaded) Q.
+    pub q: f64,
+    /// Approximate 3 dB bandwidth in Hz, f₀ / Q.
+    pub bandwidth: f64,
+}
+
+fn angular_frequency(frequency: f64) -> f64 {
+    2.0 * PI * frequency
+}
+
+/// Component realizing a series reactance `x` (ohms).
+fn series_component(x: f64, omega: f64) -> Component {
+    if x >= 0.0 {
+        Component::Inductor(x / omega)
+    } else {
+        Component::Capacitor(-1.0 / (omega * x))
+    }
+}
+
+/// Component realizing a shunt susceptance `b` (siemens).
+fn shunt_component(b: f64, omega: f64) -> Component {
+    if b >= 0.0 {
+        Component::Capacitor(b / omega)
+    } else {
+        Component::Inductor(-1.0 / (omega * b))
+    }
+}
+
+/// Admittance G + jB of an impedance R + jX.
+fn admittance(z: Complex) -> Complex {
+    Complex::ONE / z
+}
+
+/// L-section solutions with the shunt element across `far` and the series
+/// element toward `near`, as `(series reactance, shunt susceptance, node Q)`.
+fn l_section_solutions(near: Complex, far: Complex) -> Vec<(f64, f64, f64)> {
+    let Complex { re: g, im: b_far } = admittance(far);
+    let disc = g / near.re - g * g;
+    if disc < 0.0 {
+        return Vec::new();
+    }
+
+    let root = disc.sqrt();
+    let signs: &[f64] = if root == 0.0 { &[1.0] } else { &[1.0, -1.0] };
+    signs
+        .iter()
+        .map(|&sign| {
+            let b_node = sign * root;
+            let x = b_node / (g * g + b_node * b_node) - near.im;
+            (x, b_node - b_far, b_node.abs() / g)
+        })
+        .collect()
+}
+
+/// Synthesize every L-section that conjugately matches `source` to `load`.
+///
+/// Impedances are R + jX in ohms. Up to four solutions are returned: a
+/// lowpass-like and highpass-like network for each shunt placement that is
+/// realizable (the shunt element must sit on the higher-resistance side for
+/// real terminations).
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::complex::Complex;
+/// use rfconversions::matching::{l_section, Component, LTopology};
+/// // 50 Ω → 100 Ω at 100 MHz
+/// let solutions = l_section(Complex::new(50.0, 0.0), Complex::new(100.0, 0.0), 100.0e6);
+/// assert_eq!(solutions.len(), 2);
+/// let lp = solutions[0];
+/// assert_eq!(lp.topology, LTopology::ShuntAtLoad);
+/// assert!((lp.q - 1.0).abs() < 1e-12);
+/// let Component::Inductor(l) = lp.series else {
+///     panic!("lowpass L-section should have a series inductor");
+/// };
+/// assert!((l - 79.577e-9).abs() < 1e-12);
+/// ```
+#[doc(alias = "L-network")]
+#[doc(alias = "impedance match")]
+#[must_use]
+pub fn l_section(source: Complex, load: Complex, frequency: f64) -> Vec<LMatch> {
+    let omega = angular_frequency(frequency);
+    let at_load = l_section_solutions(source, load)
+        .into_iter()
+        .map(|s| (LTopology::ShuntAtLoad, s));
+    let at_source = l_section_solutions(load, source)
+        .into_iter()
+        .map(|s| (LTopology::ShuntAtSource, s));
+
+    at_load
+        .chain(at_source)
+        .map(|(topology, (x, b, q))| LMatch {
+            topology,
+            series: series_component(x, omega),
+            shunt: shunt_component(b, omega),
+            q,
+            bandwidth: frequency / q,
+        })
+        .collect()
+}
+
+/// Minimum Q a Pi or T network needs to match resistances `r1` and `r2`.
+///
+/// Q_min = √(R_high / R_low − 1), the Q of the equivalent L-section.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::matching::minimum_q;
+/// assert!((minimum_q(50.0, 200.0) - 3.0_f64.sqrt()).abs() < 1e-12);
+/// ```
+#[must_use]
+pub fn minimum_q(r1: f64, r2: f64) -> f64 {
+    (r1.max(r2) / r1.min(r2) - 1.0).sqrt()
+}
+
+/// Synthesize a Pi network matching `source` to `load` with loaded Q `q`.
+///
+/// The network is two back-to-back L-sections through a virtual resistance
+/// R = R_high / (Q² + 1). Source and load reactances are absorbed into the
+/// adjacent shunt elements.
+///
+/// # Panics
+///
+/// Panics if `q` is below [`minimum_q`] for the terminations' parallel resistances.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::complex::Complex;
+/// use rfconversions::matching::{pi_network, MatchResponse};
+/// let (source, load) = (Complex::new(50.0, 0.0), Complex::new(200.0, 0.0));
+/// let pi = pi_network(source, load, 100.0e6, 5.0, MatchResponse::Lowpass);
+/// assert_eq!(pi.bandwidth, 20.0e6);
+/// ```
+#[doc(alias = "Pi-network")]
+#[must_use]
+pub fn pi_network(
+    source: Complex,
+    load: Complex,
+    frequency: f64,
+    q: f64,
+    response: MatchResponse,
+) -> PiMatch {
+    let Complex { re: g_s, im: b_s } = admittance(source);
+    let Complex { re: g_l, im: b_l } = admittance(load);
+    let (r_s, r_l) = (1.0 / g_s, 1.0 / g_l);
+    assert!(
+        q >= minimum_q(r_s, r_l),
+        "q is below the minimum for these terminations"
+    );
+
+    let r_virtual = r_s.max(r_l) / (q * q + 1.0);
+    let q_s = (r_s / r_virtual - 1.0).sqrt();
+    let q_l = (r_l / r_virtual - 1.0).sqrt();
+    let sign = match response {
+        MatchResponse::Lowpass => 1.0,
+        MatchResponse::Highpass => -1.0,
+    };
+
+    let omega = angular_frequency(frequency);
+    PiMatch {
+        shunt_source: shunt_component(sign * q_s / r_s - b_s, omega),
+        series: series_component(sign * (q_s + q_l) * r_virtual, omega),
+        shunt_load: shunt_component(sign * q_l / r_l - b_l, omega),
+        q,
+        bandwidth: frequency / q,
+    }
+}
+
+/// Synthesize a T network matching `source` to `load` with loaded Q `q`.
+///
+/// The network is two back-to-back L-sections through a virtual resistance
+/// R = R_low · (Q² + 1). Source and load reactances are absorbed into the
+/// adjacent series elements.
+///
+/// # Panics
+///
+/// Panics if `q` is below [`minimum_q`] for the terminations' resistances.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::complex::Complex;
+/// use rfconversions::matching::{t_network, MatchResponse};
+/// let (source, load) = (Complex::new(50.0, 0.0), Complex::new(10.0, 0.0));
+/// let t = t_network(source, load, 1.0e9, 4.0, MatchResponse::Highpass);
+/// assert_eq!(t.bandwidth, 250.0e6);
+/// ```
+#[doc(alias = "T-network")]
+#[doc(alias = "Tee")]
+#[must_use]
+pub fn t_network(
+    source: Complex,
+    load: Complex,
+    frequency: f64,
+    q: f64,
+    response: MatchResponse,
+) -> TMatch {
+    let Complex { re: r_s, im: x_s } = source;
+    let Complex { re: r_l, im: x_l } = load;
+    assert!(
+        q >= minimum_q(r_s, r_l),
+        "q is below the minimum for these terminations"
+    );
+
+    let r_virtual = r_s.min(r_l) * (q * q + 1.0);
+    let q_s = (r_virtual / r_s - 1.0).sqrt();
+    let q_l = (r_virtual / r_l - 1.0).sqrt();
+    let sign = match response {
+        MatchResponse::Lowpass => 1.0,
+        MatchResponse::Highpass => -1.0,
+    };
+
+    let omega = angular_frequency(frequency);
+    TMatch {
+        series_source: series_component(sign * q_s * r_s - x_s, omega),
+        shunt: shunt_component(sign * (q_s + q_l) / r_virtual, omega),
+        series_load: series_component(sign * q_l * r_l - x_l, omega),
+        q,
+        bandwidth: frequency / q,
+    }
+}
+
+/// Characteristic impedance of a quarter-wave transformer, Z_T = √(Z₀·R_L).
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::matching::quarter_wave_impedance;
+/// assert_eq!(quarter_wave_impedance(50.0, 200.0), 100.0);
+/// ```
+#[doc(alias = "λ/4")]
+#[must_use]
+pub fn quarter_wave_impedance(z0: f64, load_resistance: f64) -> f64 {
+    (z0 * load_resistance).sqrt()
+}
+
+/// Bandwidth (Hz) over which a single-section quarter-wave transformer keeps
+/// VSWR at or below `max_vswr`.
+///
+/// Δf = f₀ · (2 − (4/π)·acos(Γm/√(1 − Γm²) · 2√(Z₀R_L)/|R_L − Z₀|))
+///
+/// If the unmatched load already meets `max_vswr` the full 2·f₀ span is returned.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::matching::quarter_wave_bandwidth;
+/// // 50 Ω → 100 Ω at 1 GHz, VSWR ≤ 1.5: ~784 MHz
+/// let bw = quarter_wave_bandwidth(1.0e9, 50.0, 100.0, 1.5);
+/// assert!((bw - 784.0e6).abs() < 1.0e6);
+/// ```
+#[doc(alias = "λ/4")]
+#[must_use]
+pub fn quarter_wave_bandwidth(frequency: f64, z0: f64, load_resistance: f64, max_vswr: f64) -> f64 {
+    let gamma_max = crate::smith::reflection_magnitude_from_vswr(max_vswr);
+    let arg =
+        gamma_max / (1.0 - gamma_max * gamma_max).sqrt() * 2.0 * (z0 * load_resistance).sqrt()
+            / (load_resistance - z0).abs();
+    frequency * (2.0 - 4.0 / PI * arg.min(1.0).acos())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Component, LTopology, MatchResponse};
+    use crate::complex::Complex;
+
+    /// Reactance of a component at angular frequency `omega`.
+    fn series_reactance(c: Component, omega: f64) -> f64 {
+        match c {
+            Component::Inductor(l) => omega * l,
+            Component::Capacitor(c) => -1.0 / (omega * c),
+        }
+    }
+
+    fn shunt_susceptance(c: Component, omega: f64) -> f64 {
+        match c {
+            Component::Inductor(l) => -1.0 / (omega * l),
+            Component::Capacitor(c) => omega * c,
+        }
+    }
+
+    fn add_series(z: Complex, dx: f64) -> Complex {
+        z + Complex::new(0.0, dx)
+    }
+
+    fn add_shunt(z: Complex, db: f64) -> Complex {
+        super::admittance(super::admittance(z) + Complex::new(0.0, db))
+    }
+
+    fn assert_conjugate(z: Complex, target: Complex) {
+        assert!(
+            (z - target.conj()).norm() < 1e-6,
+            "got {z:?}, expected conjugate of {target:?}"
+        );
+    }
+
+    /// Impedance seen by the source looking into an L-section plus load.
+    fn l_input(m: &super::LMatch, load: Complex, omega: f64) -> Complex {
+        let x = series_reactance(m.series, omega);
+        let b = shunt_susceptance(m.shunt, omega);
+        match m.topology {
+            LTopology::ShuntAtLoad => add_series(add_shunt(load, b), x),
+            LTopology::ShuntAtSource => add_shunt(add_series(load, x), b),
+        }
+    }
+
+    #[test]
+    fn l_section_real_step_up() {
+        let omega = 2.0 * std::f64::consts::PI * 100.0e6;
+        let solutions =
+            super::l_section(Complex::new(50.0, 0.0), Complex::new(100.0, 0.0), 100.0e6);
+        assert_eq!(solutions.len(), 2);
+        for m in &solutions {
+            assert_eq!(m.topology, LTopology::ShuntAtLoad);
+            assert!((m.q - 1.0).abs() < 1e-12);
+            assert!((m.bandwidth - 100.0e6).abs() < 1e-3);
+            assert_conjugate(
+                l_input(m, Complex::new(100.0, 0.0), omega),
+                Complex::new(50.0, 0.0),
+            );
+        }
+        // Lowpass solution: series L = 50 Ω / ω, shunt C = 0.01 S / ω
+        match (solutions[0].series, solutions[0].shunt) {
+            (Component::Inductor(l), Component::Capacitor(c)) => {
+                assert!((l - 79.577e-9).abs() < 1e-12);
+                assert!((c - 15.915e-12).abs() < 1e-15);
+            }
+            other => panic!("unexpected lowpass solution {other:?}"),
+        }
+        assert!(matches!(
+            (solutions[1].series, solutions[1].shunt),
+            (Component::Capacitor(_), Component::Inductor(_))
+        ));
+    }
+
+    #[test]
+    fn l_section_real_step_down_uses_shunt_at_source() {
+        let solutions = super::l_section(Complex::new(50.0, 0.0), Complex::new(10.0, 0.0), 1.0e9);
+        assert_eq!(solutions.len(), 2);
+        assert!(solutions
+            .iter()
+            .all(|m| m.topology == LTopology::ShuntAtSource));
+        assert!((solutions[0].q - 2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn l_section_complex_load_all_four_topologies() {
+        // Load inside the unit-conductance and unit-resistance circles' overlap
+        let source = Complex::new(50.0, 0.0);
+        let load = Complex::new(40.0, 30.0);
+        let omega = 2.0 * std::f64::consts::PI * 2.4e9;
+        let solutions = super::l_section(source, load, 2.4e9);
+        assert_eq!(solutions.len(), 4);
+        for m in &solutions {
+            assert_conjugate(l_input(m, load, omega), source);
+        }
+    }
+
+    #[test]
+    fn l_section_complex_source() {
+        let source = Complex::new(25.0, -15.0);
+        let load = Complex::new(75.0, 20.0);
+        let omega = 2.0 * std::f64::consts::PI * 900.0e6;
+        let solutions = super::l_section(source, load, 900.0e6);
+        assert!(!solutions.is_empty());
+        for m in &solutions {
+            assert_conjugate(l_input(m, load, omega), source);
+        }
+    }
+
+    #[test]
+    fn l_section_equal_resistances_is_trivial() {
+        let solutions = super::l_section(Complex::new(50.0, 0.0), Complex::new(50.0, 0.0), 1.0e9);
+        assert!(solutions.iter().all(|m| m.q == 0.0));
+        assert!(matches!(solutions[0].series, Component::Inductor(l) if l == 0.0));
+        assert!(matches!(solutions[0].shunt, Component::Capacitor(c) if c == 0.0));
+    }
+
+    #[test]
+    fn pi_network_matches() {
+        let source = Complex::new(50.0, 0.0);
+        let load = Complex::new(200.0, 0.0);
+        let omega = 2.0 * std::f64::consts::PI * 100.0e6;
+        for response in [MatchResponse::Lowpass, MatchResponse::Highpass] {
+            let pi = super::pi_network(source, load, 100.0e6, 5.0, response);
+            let z = add_shunt(load, shunt_susceptance(pi.shunt_load, omega));
+            let z = add_series(z, series_reactance(pi.series, omega));
+            let z = add_shunt(z, shunt_susceptance(pi.shunt_source, omega));
+            assert_conjugate(z, source);
+        }
+    }
+
+    #[test]
+    fn pi_network_absorbs_load_reactance() {
+        let source = Complex::new(50.0, 0.0);
+        let load = Complex::new(100.0, -50.0);
+        let omega = 2.0 * std::f64::consts::PI * 1.0e9;
+        let pi = super::pi_network(source, load, 1.0e9, 3.0, MatchResponse::Lowpass);
+        let z = add_shunt(load, shunt_susceptance(pi.shunt_load, omega));
+        let z = add_series(z, series_reactance(pi.series, omega));
+        let z = add_shunt(z, shunt_susceptance(pi.shunt_source, omega));
+        assert_conjugate(z, source);
+    }
+
+    #[test]
+    #[should_panic(expected = "q is below the minimum for these terminations")]
+    fn pi_network_low_q_panics() {
+        let _ = super::pi_network(
+            Complex::new(50.0, 0.0),
+            Complex::new(200.0, 0.0),
+            1.0e9,
+            1.0,
+            MatchResponse::Lowpass,
+        );
+    }
+
+    #[test]
+    fn t_network_matches() {
+        let source = Complex::new(50.0, 10.0);
+        let load = Complex::new(10.0, -5.0);
+        let omega = 2.0 * std::f64::consts::PI * 1.0e9;
+        for response in [MatchResponse::Lowpass, MatchResponse::Highpass] {
+            let t = super::t_network(source, load, 1.0e9, 4.0, response);
+            let z = add_series(load, series_reactance(t.series_load, omega));
+            let z = add_shunt(z, shunt_susceptance(t.shunt, omega));
+            let z = add_series(z, series_reactance(t.series_source, omega));
+            assert_conjugate(z, source);
+        }
+    }
+
+    #[test]
+    fn minimum_q_is_symmetric() {
+        assert_eq!(super::minimum_q(50.0, 10.0), super::minimum_q(10.0, 50.0));
+        assert_eq!(super::minimum_q(50.0, 10.0), 2.0);
+    }
+
+    #[test]
+    fn quarter_wave_impedance_geometric_mean() {
+        assert_eq!(
+            super::quarter_wave_impedance(50.0, 100.0),
+            50.0 * 2.0_f64.sqrt()
+        );
+    }
+
+    #[test]
+    fn quarter_wave_bandwidth_pozar_example() {
+        // Pozar example 5.7: 10 Ω load on a 50 Ω line at 3 GHz, VSWR 1.5 → Δf/f₀ ≈ 29%
+        let bw = super::quarter_wave_bandwidth(3.0e9, 50.0, 10.0, 1.5);
+        assert!((bw / 3.0e9 - 0.293).abs() < 0.001, "got {bw}");
+    }
+
+    #[test]
+    fn quarter_wave_bandwidth_narrows_with_larger_ratio() {
+        let small = super::quarter_wave_bandwidth(1.0e9, 50.0, 100.0, 1.5);
+        let large = super::quarter_wave_bandwidth(1.0e9, 50.0, 500.0, 1.5);
+        assert!(large < small);
+    }
+
+    #[test]
+    fn quarter_wave_bandwidth_already_matched() {
+        let bw = super::quarter_wave_bandwidth(1.0e9, 50.0, 55.0, 2.0);
+        assert_eq!(bw, 2.0e9);
+    }
+}
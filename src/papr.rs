@@ -0,0 +1,283 @@
+use crate::ber::Modulation;
+use crate::p1db::output_to_input_db;
+use crate::power::{db_to_linear, linear_to_db};
+
+/// Envelope crest factor (peak-to-RMS envelope ratio) from an envelope
+/// PAPR in dB. A CW carrier is 1 (0 dB); the RF sine's own √2 is not
+/// counted.
+///
+/// CF = √(10^(PAPR/10))
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::papr::crest_factor;
+/// // Two equal tones: 3.01 dB ↔ √2
+/// assert!((crest_factor(3.0103) - 2.0_f64.sqrt()).abs() < 1e-4);
+/// ```
+#[doc(alias = "crest factor")]
+#[must_use]
+pub fn crest_factor(papr_db: f64) -> f64 {
+    db_to_linear(papr_db).sqrt()
+}
+
+/// Envelope PAPR in dB from an envelope crest factor (peak-to-RMS
+/// envelope ratio).
+///
+/// PAPR = 20·log₁₀(CF)
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::papr::papr_db_from_crest_factor;
+/// assert!((papr_db_from_crest_factor(2.0) - 6.0206).abs() < 1e-4);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `crest_factor` is less than 1.
+#[must_use]
+pub fn papr_db_from_crest_factor(crest_factor: f64) -> f64 {
+    assert!(crest_factor >= 1.0, "crest factor must be at least 1");
+    20.0 * crest_factor.log10()
+}
+
+/// Peak envelope power (dBm) of a signal with a given average power and
+/// envelope PAPR.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::papr::peak_power_dbm;
+/// assert_eq!(peak_power_dbm(20.0, 9.5), 29.5);
+/// ```
+#[doc(alias = "PEP")]
+#[must_use]
+pub fn peak_power_dbm(average_dbm: f64, papr_db: f64) -> f64 {
+    average_dbm + papr_db
+}
+
+/// Average power (dBm) of a signal with a given peak envelope power and
+/// envelope PAPR.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::papr::average_power_dbm;
+/// assert_eq!(average_power_dbm(29.5, 9.5), 20.0);
+/// ```
+#[must_use]
+pub fn average_power_dbm(peak_dbm: f64, papr_db: f64) -> f64 {
+    peak_dbm - papr_db
+}
+
+/// Envelope PAPR (dB) of N equal-amplitude tones with aligned phases, the
+/// worst case. One tone (CW) is 0 dB and two tones are 3.01 dB.
+///
+/// PAPR = 10·log₁₀(N)
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::papr::multitone_papr_db;
+/// assert_eq!(multitone_papr_db(1), 0.0);
+/// assert!((multitone_papr_db(2) - 3.0103).abs() < 1e-4);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `tones` is zero.
+#[doc(alias = "two-tone")]
+#[must_use]
+pub fn multitone_papr_db(tones: u32) -> f64 {
+    assert!(tones >= 1, "at least one tone is required");
+    linear_to_db(tones as f64)
+}
+
+/// Envelope PAPR (dB) of a constellation with equiprobable symbols, before
+/// pulse shaping.
+///
+/// Constant-envelope PSK and FSK are 0 dB, OOK is 3.01 dB and square M-QAM
+/// is 3(√M − 1)/(√M + 1).
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::ber::Modulation;
+/// use rfconversions::papr::constellation_papr_db;
+/// assert!((constellation_papr_db(Modulation::Qam(16)) - 2.5527).abs() < 1e-4);
+/// assert!((constellation_papr_db(Modulation::Qam(64)) - 3.6798).abs() < 1e-4);
+/// assert_eq!(constellation_papr_db(Modulation::Qpsk), 0.0);
+/// ```
+///
+/// # Panics
+///
+/// Panics if the modulation order is invalid.
+#[must_use]
+pub fn constellation_papr_db(modulation: Modulation) -> f64 {
+    // Validates the order.
+    let _ = modulation.bits_per_symbol();
+    match modulation {
+        Modulation::Bpsk | Modulation::Qpsk | Modulation::Psk(_) | Modulation::Fsk(_) => 0.0,
+        Modulation::Ook => linear_to_db(2.0),
+        Modulation::Qam(m) => {
+            let side = (m as f64).sqrt();
+            linear_to_db(3.0 * (side - 1.0) / (side + 1.0))
+        }
+    }
+}
+
+/// Probability that the envelope PAPR of an OFDM symbol with N
+/// subcarriers exceeds a threshold (dB), for Nyquist-sampled Gaussian
+/// envelopes.
+///
+/// CCDF = 1 − (1 − e^(−γ))^N
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::papr::ofdm_papr_ccdf;
+/// let p = ofdm_papr_ccdf(10.0, 256);
+/// assert!((p - 0.0116).abs() < 1e-4);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `subcarriers` is zero.
+#[doc(alias = "CCDF")]
+#[must_use]
+pub fn ofdm_papr_ccdf(papr_db: f64, subcarriers: u32) -> f64 {
+    assert!(subcarriers >= 1, "at least one subcarrier is required");
+    let gamma = db_to_linear(papr_db);
+    // 1 − (1 − x)^N without cancellation for small x.
+    -(subcarriers as f64 * (-(-gamma).exp()).ln_1p()).exp_m1()
+}
+
+/// Envelope PAPR (dB) of an OFDM signal with N subcarriers that is
+/// exceeded with a given probability; the inverse of [`ofdm_papr_ccdf`].
+///
+/// γ = −ln(1 − (1 − p)^(1/N))
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::papr::ofdm_papr_db;
+/// // 1024 subcarriers at 1e-4 → ~12.1 dB
+/// assert!((ofdm_papr_db(1024, 1e-4) - 12.08).abs() < 0.01);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `subcarriers` is zero or `probability` is outside (0, 1).
+#[must_use]
+pub fn ofdm_papr_db(subcarriers: u32, probability: f64) -> f64 {
+    assert!(subcarriers >= 1, "at least one subcarrier is required");
+    assert!(
+        probability > 0.0 && probability < 1.0,
+        "probability must be in (0, 1)"
+    );
+    // 1 − (1 − p)^(1/N), again without cancellation.
+    let per_sample = -((-probability).ln_1p() / subcarriers as f64).exp_m1();
+    linear_to_db(-per_sample.ln())
+}
+
+/// Highest average output power (dBm) that keeps the peak envelope power
+/// at or below the output P1dB, for an envelope PAPR (dB).
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::papr::max_average_output_dbm;
+/// assert_eq!(max_average_output_dbm(30.0, 8.0), 22.0);
+/// ```
+#[doc(alias = "backoff")]
+#[must_use]
+pub fn max_average_output_dbm(output_p1db_dbm: f64, papr_db: f64) -> f64 {
+    average_power_dbm(output_p1db_dbm, papr_db)
+}
+
+/// Highest average input power (dBm) that keeps the peak envelope power at
+/// or below the input P1dB of an amplifier with a given small-signal gain,
+/// for an envelope PAPR (dB).
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::papr::max_average_input_dbm;
+/// // OP1dB 30 dBm, 20 dB gain → IP1dB 11 dBm; 8 dB PAPR → 3 dBm
+/// assert_eq!(max_average_input_dbm(30.0, 20.0, 8.0), 3.0);
+/// ```
+#[doc(alias = "input backoff")]
+#[must_use]
+pub fn max_average_input_dbm(output_p1db_dbm: f64, gain_db: f64, papr_db: f64) -> f64 {
+    average_power_dbm(output_to_input_db(output_p1db_dbm, gain_db), papr_db)
+}
+
+/// Margin (dB) between the peak envelope power and the output P1dB when
+/// running at an average output power with an envelope PAPR (dB); negative
+/// when the peaks compress.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::papr::peak_margin_db;
+/// assert_eq!(peak_margin_db(20.0, 30.0, 8.0), 2.0);
+/// ```
+#[must_use]
+pub fn peak_margin_db(average_output_dbm: f64, output_p1db_dbm: f64, papr_db: f64) -> f64 {
+    output_p1db_dbm - peak_power_dbm(average_output_dbm, papr_db)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ber::Modulation;
+
+    #[test]
+    fn crest_factor_round_trip() {
+        let papr = super::papr_db_from_crest_factor(super::crest_factor(7.3));
+        assert!((papr - 7.3).abs() < 1e-12);
+    }
+
+    #[test]
+    fn cw_has_unity_crest_factor() {
+        // Envelope convention: CW is 0 dB, not the 3.01 dB of an RF sine.
+        let cw = super::multitone_papr_db(1);
+        assert_eq!(super::crest_factor(cw), 1.0);
+        assert_eq!(super::constellation_papr_db(Modulation::Bpsk), cw);
+    }
+
+    #[test]
+    fn qam_papr_approaches_4_77_db() {
+        // 3(√M − 1)/(√M + 1) → 3 as M grows
+        let p = super::constellation_papr_db(Modulation::Qam(4096));
+        assert!(p < 4.7712 && p > 4.5);
+        assert!(super::constellation_papr_db(Modulation::Qam(4)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn ofdm_ccdf_inverse() {
+        for &(n, p) in &[(64, 1e-3), (1024, 1e-4), (2048, 1e-6)] {
+            let papr = super::ofdm_papr_db(n, p);
+            assert!((super::ofdm_papr_ccdf(papr, n) / p - 1.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn ofdm_ccdf_of_single_subcarrier_is_rayleigh() {
+        // P(|x|²/σ² > γ) = e^(−γ)
+        let p = super::ofdm_papr_ccdf(3.0, 1);
+        assert!((p - (-crate::power::db_to_linear(3.0)).exp()).abs() < 1e-15);
+    }
+
+    #[test]
+    fn backoff_consistent_with_peak_margin() {
+        let avg = super::max_average_output_dbm(33.0, 9.5);
+        assert_eq!(super::peak_margin_db(avg, 33.0, 9.5), 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "probability must be in (0, 1)")]
+    fn ofdm_rejects_probability_of_one() {
+        let _ = super::ofdm_papr_db(64, 1.0);
+    }
+}
@@ -0,0 +1,482 @@
+use std::f64::consts::PI;
+
+/// Single-sideband phase noise L(f) in dBc/Hz at an offset, interpolated
+/// log-linearly (straight lines on a log-frequency plot) between profile
+/// points.
+///
+/// The profile is a slice of `(offset_hz, dbc_per_hz)` pairs with strictly
+/// increasing offsets. Offsets outside the profile extend the end segments.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::phase_noise::phase_noise_at;
+/// let profile = [(1e3, -90.0), (1e5, -130.0)];
+/// // −20 dB/decade slope → −110 dBc/Hz at 10 kHz
+/// assert!((phase_noise_at(&profile, 1e4) - (-110.0)).abs() < 1e-12);
+/// ```
+///
+/// # Panics
+///
+/// Panics if the profile has fewer than two points or offsets are not
+/// positive and strictly increasing.
+#[doc(alias = "L(f)")]
+#[must_use]
+pub fn phase_noise_at(profile: &[(f64, f64)], offset: f64) -> f64 {
+    check_profile(profile);
+    let i = profile
+        .windows(2)
+        .position(|w| offset <= w[1].0)
+        .unwrap_or(profile.len() - 2);
+    let (f1, l1) = profile[i];
+    let (f2, l2) = profile[i + 1];
+    l1 + (l2 - l1) * (offset / f1).log10() / (f2 / f1).log10()
+}
+
+/// Integrated single-sideband phase noise in dBc over an offset range.
+///
+/// A = ∫ L(f) df, with L(f) = L₁·(f/f₁)^k between profile points
+/// (k = ΔL(dB) / (10·log₁₀(f₂/f₁))), integrated exactly per segment.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::phase_noise::integrated_phase_noise_dbc;
+/// // Flat −100 dBc/Hz over 1 kHz – 1 MHz → −100 + 10·log₁₀(999e3) dBc
+/// let profile = [(1e3, -100.0), (1e6, -100.0)];
+/// let a = integrated_phase_noise_dbc(&profile, 1e3, 1e6);
+/// assert!((a - (-40.0)).abs() < 0.01);
+/// ```
+///
+/// # Panics
+///
+/// Panics if the profile is invalid, or the range is not increasing or lies
+/// outside the profile.
+#[doc(alias = "integrated phase noise")]
+#[must_use]
+pub fn integrated_phase_noise_dbc(profile: &[(f64, f64)], start: f64, stop: f64) -> f64 {
+    10.0 * integrate(profile, start, stop, 0).log10()
+}
+
+/// RMS phase error in degrees over an offset range.
+///
+/// φ_rms = √(2·∫ L(f) df) radians, counting both sidebands.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::phase_noise::rms_phase_error_deg;
+/// let profile = [(1e3, -100.0), (1e6, -100.0)];
+/// // A = 1e-4 → φ = √2e-4 rad = 0.81°
+/// let phi = rms_phase_error_deg(&profile, 1e3, 1e6);
+/// assert!((phi - 0.81).abs() < 0.01);
+/// ```
+///
+/// # Panics
+///
+/// Panics under the same conditions as [`integrated_phase_noise_dbc`].
+#[doc(alias = "phase jitter")]
+#[must_use]
+pub fn rms_phase_error_deg(profile: &[(f64, f64)], start: f64, stop: f64) -> f64 {
+    rms_phase_error_rad(profile, start, stop).to_degrees()
+}
+
+/// RMS jitter in seconds over an offset range for a carrier frequency (Hz).
+///
+/// t_j = φ_rms / (2π·f_c)
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::phase_noise::rms_jitter;
+/// let profile = [(1e3, -100.0), (1e6, -100.0)];
+/// let tj = rms_jitter(&profile, 1e3, 1e6, 1e9);
+/// assert!((tj - 2.25e-12).abs() < 0.01e-12);
+/// ```
+///
+/// # Panics
+///
+/// Panics under the same conditions as [`integrated_phase_noise_dbc`], or
+/// if `carrier` is not positive.
+#[doc(alias = "jitter")]
+#[must_use]
+pub fn rms_jitter(profile: &[(f64, f64)], start: f64, stop: f64, carrier: f64) -> f64 {
+    assert!(carrier > 0.0, "carrier frequency must be positive");
+    rms_phase_error_rad(profile, start, stop) / (2.0 * PI * carrier)
+}
+
+/// Residual FM (RMS frequency deviation, Hz) over an offset range.
+///
+/// Δf_rms = √(2·∫ L(f)·f² df)
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::phase_noise::residual_fm;
+/// // −20 dB/decade: L(f)·f² is flat at 10^(−9) · 1e6 = 1e-3 Hz²/Hz
+/// let profile = [(1e3, -90.0), (1e5, -130.0)];
+/// let fm = residual_fm(&profile, 1e3, 1e5);
+/// assert!((fm - (2.0 * 1e-3 * 99e3_f64).sqrt()).abs() < 1e-9);
+/// ```
+///
+/// # Panics
+///
+/// Panics under the same conditions as [`integrated_phase_noise_dbc`].
+#[doc(alias = "residual FM")]
+#[must_use]
+pub fn residual_fm(profile: &[(f64, f64)], start: f64, stop: f64) -> f64 {
+    (2.0 * integrate(profile, start, stop, 2)).sqrt()
+}
+
+/// Phase noise (dBc/Hz) after frequency multiplication by `factor`.
+///
+/// L_out = L_in + 20·log₁₀(N)
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::phase_noise::multiply_phase_noise;
+/// // ×10 adds 20 dB
+/// assert!((multiply_phase_noise(-150.0, 10.0) - (-130.0)).abs() < 1e-12);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `factor` is not positive.
+#[doc(alias = "frequency multiplier")]
+#[must_use]
+pub fn multiply_phase_noise(dbc_per_hz: f64, factor: f64) -> f64 {
+    assert!(factor > 0.0, "factor must be positive");
+    dbc_per_hz + 20.0 * factor.log10()
+}
+
+/// Phase noise (dBc/Hz) after frequency division by `divisor`, ignoring the
+/// divider's own noise floor.
+///
+/// L_out = L_in − 20·log₁₀(N)
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::phase_noise::divide_phase_noise;
+/// // ÷2 removes 6.02 dB
+/// assert!((divide_phase_noise(-100.0, 2.0) - (-106.0206)).abs() < 1e-4);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `divisor` is not positive.
+#[doc(alias = "frequency divider")]
+#[must_use]
+pub fn divide_phase_noise(dbc_per_hz: f64, divisor: f64) -> f64 {
+    assert!(divisor > 0.0, "divisor must be positive");
+    dbc_per_hz - 20.0 * divisor.log10()
+}
+
+/// Translate a whole profile from one carrier frequency to another (Hz) by
+/// ideal multiplication or division.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::phase_noise::scale_profile;
+/// let tcxo = [(10.0, -90.0), (1e3, -140.0)];
+/// let at_1ghz = scale_profile(&tcxo, 10e6, 1e9);
+/// assert!((at_1ghz[0].1 - (-50.0)).abs() < 1e-12);
+/// ```
+#[must_use]
+pub fn scale_profile(
+    profile: &[(f64, f64)],
+    from_frequency: f64,
+    to_frequency: f64,
+) -> Vec<(f64, f64)> {
+    profile
+        .iter()
+        .map(|&(f, l)| (f, multiply_phase_noise(l, to_frequency / from_frequency)))
+        .collect()
+}
+
+/// Integer-N PLL phase noise model.
+///
+/// The reference and PFD/divider noise are multiplied up to the output and
+/// shaped by the closed-loop response of a type-2, second-order loop,
+///
+/// H(s) = (2ζωₙs + ωₙ²) / (s² + 2ζωₙs + ωₙ²),
+///
+/// while the free-running VCO noise is shaped by 1 − H(s). The PFD and
+/// divider floor is given as the normalized 1 Hz figure of merit, so the
+/// in-band floor at the output is FOM + 10·log₁₀(f_PFD) + 20·log₁₀(f_out / f_PFD).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pll {
+    /// Reference oscillator frequency in Hz.
+    pub reference_frequency: f64,
+    /// Phase detector comparison frequency in Hz.
+    pub pfd_frequency: f64,
+    /// Output frequency in Hz.
+    pub output_frequency: f64,
+    /// Closed-loop −3 dB bandwidth in Hz.
+    pub loop_bandwidth: f64,
+    /// Loop damping factor ζ (0.707 is typical).
+    pub damping: f64,
+    /// Reference oscillator L(f) profile at `reference_frequency`.
+    pub reference: Vec<(f64, f64)>,
+    /// Free-running VCO L(f) profile at `output_frequency`.
+    pub vco: Vec<(f64, f64)>,
+    /// Normalized PFD/divider figure of merit in dBc/Hz (e.g. −226).
+    pub pfd_figure_of_merit: f64,
+}
+
+impl Pll {
+    /// Output phase noise in dBc/Hz at an offset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfconversions::phase_noise::Pll;
+    /// let pll = Pll {
+    ///     reference_frequency: 100e6,
+    ///     pfd_frequency: 100e6,
+    ///     output_frequency: 5e9,
+    ///     loop_bandwidth: 200e3,
+    ///     damping: 0.707,
+    ///     reference: vec![(1e2, -130.0), (1e3, -150.0), (1e4, -160.0), (1e7, -165.0)],
+    ///     vco: vec![(1e3, -50.0), (1e5, -100.0), (1e6, -125.0), (1e7, -145.0)],
+    ///     pfd_figure_of_merit: -226.0,
+    /// };
+    /// // In-band: the PFD floor (−226 + 80 + 34 = −112) and the ×50
+    /// // reference (−150 + 34 = −116) add up
+    /// assert!((pll.phase_noise_at(1e3) - (-110.51)).abs() < 0.01);
+    /// // Far out: the VCO dominates
+    /// assert!((pll.phase_noise_at(10e6) - (-145.0)).abs() < 2.0);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if any frequency, the bandwidth or the damping is not
+    /// positive, or a profile is invalid.
+    #[must_use]
+    pub fn phase_noise_at(&self, offset: f64) -> f64 {
+        assert!(
+            self.reference_frequency > 0.0
+                && self.pfd_frequency > 0.0
+                && self.output_frequency > 0.0,
+            "frequencies must be positive"
+        );
+        assert!(self.loop_bandwidth > 0.0, "loop bandwidth must be positive");
+        assert!(self.damping > 0.0, "damping must be positive");
+
+        let (closed, error) = self.loop_response(offset);
+        let reference = 10.0_f64.powf(
+            multiply_phase_noise(
+                phase_noise_at(&self.reference, offset),
+                self.output_frequency / self.reference_frequency,
+            ) / 10.0,
+        );
+        let pfd = 10.0_f64.powf(
+            (self.pfd_figure_of_merit
+                + 10.0 * self.pfd_frequency.log10()
+                + 20.0 * (self.output_frequency / self.pfd_frequency).log10())
+                / 10.0,
+        );
+        let vco = 10.0_f64.powf(phase_noise_at(&self.vco, offset) / 10.0);
+        10.0 * (closed * (reference + pfd) + error * vco).log10()
+    }
+
+    /// Output L(f) profile evaluated at the given offsets, ready for the
+    /// integration functions in this module.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfconversions::phase_noise::{self, Pll};
+    /// let pll = Pll {
+    ///     reference_frequency: 100e6,
+    ///     pfd_frequency: 100e6,
+    ///     output_frequency: 5e9,
+    ///     loop_bandwidth: 200e3,
+    ///     damping: 0.707,
+    ///     reference: vec![(1e2, -130.0), (1e3, -150.0), (1e4, -160.0), (1e7, -165.0)],
+    ///     vco: vec![(1e3, -50.0), (1e5, -100.0), (1e6, -125.0), (1e7, -145.0)],
+    ///     pfd_figure_of_merit: -226.0,
+    /// };
+    /// let offsets: Vec<f64> = (0..=40).map(|i| 10.0_f64.powf(3.0 + i as f64 / 10.0)).collect();
+    /// let profile = pll.output_profile(&offsets);
+    /// let jitter = phase_noise::rms_jitter(&profile, 1e3, 1e7, 5e9);
+    /// assert!(jitter > 10e-15 && jitter < 1e-12);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics under the same conditions as [`Pll::phase_noise_at`].
+    #[must_use]
+    pub fn output_profile(&self, offsets: &[f64]) -> Vec<(f64, f64)> {
+        offsets
+            .iter()
+            .map(|&f| (f, self.phase_noise_at(f)))
+            .collect()
+    }
+
+    /// |H(jω)|² and |1 − H(jω)|² at an offset.
+    fn loop_response(&self, offset: f64) -> (f64, f64) {
+        let z = self.damping;
+        let a = 1.0 + 2.0 * z * z;
+        // ω₃dB = ωₙ·√(1 + 2ζ² + √((1 + 2ζ²)² + 1))
+        let wn = 2.0 * PI * self.loop_bandwidth / (a + (a * a + 1.0).sqrt()).sqrt();
+        let w = 2.0 * PI * offset;
+        let den = (wn * wn - w * w).powi(2) + (2.0 * z * wn * w).powi(2);
+        let closed = (wn.powi(4) + (2.0 * z * wn * w).powi(2)) / den;
+        let error = w.powi(4) / den;
+        (closed, error)
+    }
+}
+
+fn rms_phase_error_rad(profile: &[(f64, f64)], start: f64, stop: f64) -> f64 {
+    (2.0 * integrate(profile, start, stop, 0)).sqrt()
+}
+
+fn check_profile(profile: &[(f64, f64)]) {
+    assert!(
+        profile.len() >= 2,
+        "phase noise profile needs at least two points"
+    );
+    assert!(
+        profile[0].0 > 0.0 && profile.windows(2).all(|w| w[1].0 > w[0].0),
+        "profile offsets must be positive and strictly increasing"
+    );
+}
+
+/// ∫ L(f)·fᵐ df over [start, stop] with power-law segments.
+fn integrate(profile: &[(f64, f64)], start: f64, stop: f64, moment: i32) -> f64 {
+    check_profile(profile);
+    assert!(stop > start, "stop offset must exceed start offset");
+    assert!(
+        start >= profile[0].0 && stop <= profile[profile.len() - 1].0,
+        "integration range must lie within the profile"
+    );
+
+    profile
+        .windows(2)
+        .map(|w| {
+            let (f1, l1) = w[0];
+            let (f2, l2) = w[1];
+            let a = start.max(f1);
+            let b = stop.min(f2);
+            if b <= a {
+                return 0.0;
+            }
+            // L(f)·fᵐ = c·f^p
+            let slope = (l2 - l1) / 10.0 / (f2 / f1).log10();
+            let p = slope + moment as f64;
+            let c = 10.0_f64.powf(l1 / 10.0) / f1.powf(slope);
+            if (p + 1.0).abs() < 1e-12 {
+                c * (b / a).ln()
+            } else {
+                c / (p + 1.0) * (b.powf(p + 1.0) - a.powf(p + 1.0))
+            }
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn interpolation_hits_profile_points() {
+        let profile = [(1e2, -70.0), (1e4, -100.0), (1e6, -140.0)];
+        for &(f, l) in &profile {
+            assert!((super::phase_noise_at(&profile, f) - l).abs() < 1e-12);
+        }
+        // Extrapolates the last segment
+        assert!((super::phase_noise_at(&profile, 1e7) - (-160.0)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn one_over_f_segment_uses_log() {
+        // −10 dB/decade: L(f) = 1e-8·(1e3/f) → ∫ = 1e-5·ln(100)
+        let profile = [(1e3, -80.0), (1e5, -100.0)];
+        let a = super::integrated_phase_noise_dbc(&profile, 1e3, 1e5);
+        let expected = 10.0 * (1e-5 * 100.0_f64.ln()).log10();
+        assert!((a - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn sub_range_integrates_partial_segments() {
+        let profile = [(1e3, -100.0), (1e6, -100.0)];
+        let a = super::integrated_phase_noise_dbc(&profile, 1e4, 2e4);
+        assert!((a - (-60.0)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn numeric_cross_check() {
+        let profile = [
+            (1e3, -85.0),
+            (1e4, -95.0),
+            (1e5, -118.0),
+            (1e6, -140.0),
+            (1e7, -150.0),
+        ];
+        let exact = 10.0_f64.powf(super::integrated_phase_noise_dbc(&profile, 1e3, 1e7) / 10.0);
+        // Trapezoid on a fine log grid
+        let n = 200_000;
+        let (lo, hi) = (3.0_f64, 7.0_f64);
+        let mut sum = 0.0;
+        for i in 0..n {
+            let f1 = 10.0_f64.powf(lo + (hi - lo) * i as f64 / n as f64);
+            let f2 = 10.0_f64.powf(lo + (hi - lo) * (i + 1) as f64 / n as f64);
+            let l1 = 10.0_f64.powf(super::phase_noise_at(&profile, f1) / 10.0);
+            let l2 = 10.0_f64.powf(super::phase_noise_at(&profile, f2) / 10.0);
+            sum += 0.5 * (l1 + l2) * (f2 - f1);
+        }
+        assert!((sum / exact - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn multiply_then_divide_is_identity() {
+        let l = super::divide_phase_noise(super::multiply_phase_noise(-120.0, 7.0), 7.0);
+        assert!((l - (-120.0)).abs() < 1e-12);
+    }
+
+    fn pll() -> super::Pll {
+        super::Pll {
+            reference_frequency: 100e6,
+            pfd_frequency: 25e6,
+            output_frequency: 2.5e9,
+            loop_bandwidth: 100e3,
+            damping: 0.707,
+            reference: vec![(1e1, -100.0), (1e3, -150.0), (1e7, -170.0)],
+            vco: vec![(1e3, -60.0), (1e5, -105.0), (1e7, -150.0)],
+            pfd_figure_of_merit: -230.0,
+        }
+    }
+
+    #[test]
+    fn loop_response_is_3db_at_loop_bandwidth() {
+        let (closed, _) = pll().loop_response(100e3);
+        assert!((closed - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn pll_tracks_reference_close_in() {
+        // At 10 Hz the reference ×25 dominates: −100 + 27.96 dB
+        let l = pll().phase_noise_at(10.0);
+        assert!((l - (-72.04)).abs() < 0.01);
+    }
+
+    #[test]
+    fn pll_follows_vco_far_out() {
+        let p = pll();
+        let l = p.phase_noise_at(10e6);
+        assert!((l - super::phase_noise_at(&p.vco, 10e6)).abs() < 1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "integration range must lie within the profile")]
+    fn rejects_range_outside_profile() {
+        let _ = super::rms_phase_error_deg(&[(1e3, -90.0), (1e6, -140.0)], 100.0, 1e6);
+    }
+
+    #[test]
+    #[should_panic(expected = "profile offsets must be positive and strictly increasing")]
+    fn rejects_unsorted_profile() {
+        let _ = super::phase_noise_at(&[(1e4, -90.0), (1e3, -80.0)], 5e3);
+    }
+}
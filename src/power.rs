@@ -175,6 +175,123 @@ pub fn dbw_to_milliwatts(dbw: f64) -> f64 {
     10.0_f64.powf(dbw / 10.0) * 1000.0
 }
 
+/// Incoherent sum of powers in dBm.
+///
+/// P = 10·log₁₀(Σ 10^(Pᵢ/10))
+///
+/// An empty slice sums to −∞ dBm.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::power::sum_dbm;
+/// // Two equal signals add 3 dB
+/// assert!((sum_dbm(&[-90.0, -90.0]) - (-86.9897)).abs() < 1e-4);
+/// ```
+#[doc(alias = "power sum")]
+#[must_use]
+pub fn sum_dbm(powers_dbm: &[f64]) -> f64 {
+    milliwatts_to_dbm(powers_dbm.iter().map(|&p| dbm_to_milliwatts(p)).sum())
+}
+
+/// Remove an incoherent component from a total power, both in dBm.
+///
+/// P = 10·log₁₀(10^(P_total/10) − 10^(P_component/10))
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::power::subtract_dbm;
+/// // Noise-plus-signal of −97 dBm with −100 dBm noise leaves −100 dBm signal
+/// assert!((subtract_dbm(-96.9897, -100.0) - (-100.0)).abs() < 1e-3);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `component_dbm` exceeds `total_dbm`.
+#[must_use]
+pub fn subtract_dbm(total_dbm: f64, component_dbm: f64) -> f64 {
+    assert!(
+        component_dbm <= total_dbm,
+        "component must not exceed the total"
+    );
+    milliwatts_to_dbm(dbm_to_milliwatts(total_dbm) - dbm_to_milliwatts(component_dbm))
+}
+
+/// Coherent sum of signals given as `(power_dbm, phase_deg)` pairs, adding
+/// voltages as phasors.
+///
+/// P = 10·log₁₀|Σ √(10^(Pᵢ/10))·e^(jφᵢ)|²
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::power::coherent_sum_dbm;
+/// // In phase: +6 dB
+/// assert!((coherent_sum_dbm(&[(0.0, 0.0), (0.0, 0.0)]) - 6.0206).abs() < 1e-4);
+/// // Quadrature: +3 dB, the same as an incoherent sum
+/// assert!((coherent_sum_dbm(&[(0.0, 0.0), (0.0, 90.0)]) - 3.0103).abs() < 1e-4);
+/// ```
+#[doc(alias = "phasor sum")]
+#[must_use]
+pub fn coherent_sum_dbm(signals: &[(f64, f64)]) -> f64 {
+    let (re, im) = signals
+        .iter()
+        .fold((0.0, 0.0), |(re, im), &(dbm, phase_deg)| {
+            let amplitude = dbm_to_milliwatts(dbm).sqrt();
+            let phase = phase_deg.to_radians();
+            (re + amplitude * phase.cos(), im + amplitude * phase.sin())
+        });
+    milliwatts_to_dbm(re * re + im * im)
+}
+
+/// Average power in dBm of a list of levels, averaged in milliwatts.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::power::average_dbm;
+/// // The mean of 0 dBm and −∞ is −3 dBm, not −∞
+/// assert!((average_dbm(&[0.0, f64::NEG_INFINITY]) - (-3.0103)).abs() < 1e-4);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `powers_dbm` is empty.
+#[must_use]
+pub fn average_dbm(powers_dbm: &[f64]) -> f64 {
+    assert!(!powers_dbm.is_empty(), "powers must not be empty");
+    sum_dbm(powers_dbm) - linear_to_db(powers_dbm.len() as f64)
+}
+
+/// Level of a signal relative to a carrier, in dBc.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::power::dbm_to_dbc;
+/// assert_eq!(dbm_to_dbc(-40.0, 10.0), -50.0);
+/// ```
+#[doc(alias = "dBc")]
+#[must_use]
+pub fn dbm_to_dbc(power_dbm: f64, carrier_dbm: f64) -> f64 {
+    power_dbm - carrier_dbm
+}
+
+/// Absolute level in dBm of a signal given in dBc.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::power::dbc_to_dbm;
+/// assert_eq!(dbc_to_dbm(-50.0, 10.0), -40.0);
+/// ```
+#[doc(alias = "dBc")]
+#[must_use]
+pub fn dbc_to_dbm(dbc: f64, carrier_dbm: f64) -> f64 {
+    carrier_dbm + dbc
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -389,4 +506,51 @@ mod tests {
         let result: f64 = super::dbw_to_dbm(dbw);
         assert_eq!(original, result);
     }
+
+    #[test]
+    fn sum_dbm_of_empty_is_negative_infinity() {
+        let total = super::sum_dbm(&[]);
+        assert!(total.is_infinite() && total < 0.0);
+    }
+
+    #[test]
+    fn sum_then_subtract_recovers_component() {
+        let total = super::sum_dbm(&[-95.0, -101.0, -110.0]);
+        let rest = super::subtract_dbm(total, -95.0);
+        assert!((rest - super::sum_dbm(&[-101.0, -110.0])).abs() < 1e-9);
+    }
+
+    #[test]
+    fn subtract_equal_powers_is_negative_infinity() {
+        assert!(super::subtract_dbm(-80.0, -80.0).is_infinite());
+    }
+
+    #[test]
+    #[should_panic(expected = "component must not exceed the total")]
+    fn subtract_rejects_larger_component() {
+        let _ = super::subtract_dbm(-80.0, -79.0);
+    }
+
+    #[test]
+    fn coherent_antiphase_cancels() {
+        let p = super::coherent_sum_dbm(&[(10.0, 0.0), (10.0, 180.0)]);
+        assert!(p < -100.0);
+    }
+
+    #[test]
+    fn coherent_single_signal_is_unchanged() {
+        let p = super::coherent_sum_dbm(&[(-12.5, 37.0)]);
+        assert!((p - (-12.5)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn average_of_equal_powers() {
+        assert!((super::average_dbm(&[7.0, 7.0, 7.0]) - 7.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn dbc_round_trip() {
+        let dbc = super::dbm_to_dbc(-63.0, 17.0);
+        assert_eq!(super::dbc_to_dbm(dbc, 17.0), -63.0);
+    }
 }
@@ -0,0 +1,633 @@
+use std::fmt::Write;
+
+use crate::complex::Complex;
+
+/// Reflection coefficient Γ = (z − 1)/(z + 1) of a normalized impedance.
+///
+/// `z` is Z/Z₀.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::complex::Complex;
+/// use rfconversions::smith::impedance_to_reflection_coefficient;
+/// assert_eq!(impedance_to_reflection_coefficient(Complex::ONE), Complex::ZERO);
+/// assert_eq!(
+///     impedance_to_reflection_coefficient(Complex::new(0.0, 1.0)),
+///     Complex::new(0.0, 1.0)
+/// );
+/// ```
+#[doc(alias = "Gamma")]
+#[doc(alias = "S11")]
+#[must_use]
+pub fn impedance_to_reflection_coefficient(z: Complex) -> Complex {
+    (z - Complex::ONE) / (z + Complex::ONE)
+}
+
+/// Normalized impedance z = (1 + Γ)/(1 − Γ) of a reflection coefficient.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::complex::Complex;
+/// use rfconversions::smith::reflection_coefficient_to_impedance;
+/// let z = reflection_coefficient_to_impedance(Complex::new(1.0 / 3.0, 0.0));
+/// assert!((z.re - 2.0).abs() < 1e-12 && z.im.abs() < 1e-12);
+/// ```
+#[doc(alias = "Gamma")]
+#[must_use]
+pub fn reflection_coefficient_to_impedance(gamma: Complex) -> Complex {
+    (Complex::ONE + gamma) / (Complex::ONE - gamma)
+}
+
+/// Reflection coefficient Γ = (1 − y)/(1 + y) of a normalized admittance.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::complex::Complex;
+/// use rfconversions::smith::admittance_to_reflection_coefficient;
+/// // y = 2 is z = 0.5
+/// let gamma = admittance_to_reflection_coefficient(Complex::new(2.0, 0.0));
+/// assert!((gamma.re + 1.0 / 3.0).abs() < 1e-12);
+/// ```
+#[doc(alias = "Gamma")]
+#[must_use]
+pub fn admittance_to_reflection_coefficient(y: Complex) -> Complex {
+    (Complex::ONE - y) / (Complex::ONE + y)
+}
+
+/// Normalized admittance y = (1 − Γ)/(1 + Γ) of a reflection coefficient.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::complex::Complex;
+/// use rfconversions::smith::reflection_coefficient_to_admittance;
+/// let y = reflection_coefficient_to_admittance(Complex::ZERO);
+/// assert_eq!(y, Complex::ONE);
+/// ```
+#[doc(alias = "Gamma")]
+#[must_use]
+pub fn reflection_coefficient_to_admittance(gamma: Complex) -> Complex {
+    (Complex::ONE - gamma) / (Complex::ONE + gamma)
+}
+
+/// Magnitude of the reflection coefficient for a given VSWR, |Γ| = (S − 1)/(S + 1).
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::smith::reflection_magnitude_from_vswr;
+/// assert_eq!(reflection_magnitude_from_vswr(2.0), 1.0 / 3.0);
+/// ```
+#[doc(alias = "VSWR")]
+#[must_use]
+pub fn reflection_magnitude_from_vswr(vswr: f64) -> f64 {
+    (vswr - 1.0) / (vswr + 1.0)
+}
+
+/// VSWR for a reflection coefficient magnitude, S = (1 + |Γ|)/(1 − |Γ|).
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::smith::vswr_from_reflection_magnitude;
+/// assert_eq!(vswr_from_reflection_magnitude(0.5), 3.0);
+/// ```
+#[doc(alias = "VSWR")]
+#[must_use]
+pub fn vswr_from_reflection_magnitude(magnitude: f64) -> f64 {
+    (1.0 + magnitude) / (1.0 - magnitude)
+}
+
+/// A circle in the Γ plane.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Circle {
+    /// Center.
+    pub center: Complex,
+    /// Radius.
+    pub radius: f64,
+}
+
+/// Constant-resistance circle for normalized resistance `r`.
+///
+/// Center (r/(1 + r), 0), radius 1/(1 + r).
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::complex::Complex;
+/// use rfconversions::smith::constant_resistance_circle;
+/// let c = constant_resistance_circle(1.0);
+/// assert_eq!(c.center, Complex::new(0.5, 0.0));
+/// assert_eq!(c.radius, 0.5);
+/// ```
+#[must_use]
+pub fn constant_resistance_circle(r: f64) -> Circle {
+    Circle {
+        center: Complex::new(r / (1.0 + r), 0.0),
+        radius: 1.0 / (1.0 + r),
+    }
+}
+
+/// Constant-reactance circle for normalized reactance `x` (non-zero).
+///
+/// Center (1, 1/x), radius 1/|x|. Only the arc inside the unit circle is
+/// part of the chart.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::complex::Complex;
+/// use rfconversions::smith::constant_reactance_circle;
+/// let c = constant_reactance_circle(-2.0);
+/// assert_eq!(c.center, Complex::new(1.0, -0.5));
+/// assert_eq!(c.radius, 0.5);
+/// ```
+#[must_use]
+pub fn constant_reactance_circle(x: f64) -> Circle {
+    Circle {
+        center: Complex::new(1.0, 1.0 / x),
+        radius: 1.0 / x.abs(),
+    }
+}
+
+/// Constant-conductance circle for normalized conductance `g`.
+///
+/// Center (−g/(1 + g), 0), radius 1/(1 + g).
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::complex::Complex;
+/// use rfconversions::smith::constant_conductance_circle;
+/// assert_eq!(constant_conductance_circle(1.0).center, Complex::new(-0.5, 0.0));
+/// ```
+#[must_use]
+pub fn constant_conductance_circle(g: f64) -> Circle {
+    Circle {
+        center: Complex::new(-g / (1.0 + g), 0.0),
+        radius: 1.0 / (1.0 + g),
+    }
+}
+
+/// Constant-susceptance circle for normalized susceptance `b` (non-zero).
+///
+/// Center (−1, −1/b), radius 1/|b|.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::complex::Complex;
+/// use rfconversions::smith::constant_susceptance_circle;
+/// assert_eq!(constant_susceptance_circle(1.0).center, Complex::new(-1.0, -1.0));
+/// ```
+#[must_use]
+pub fn constant_susceptance_circle(b: f64) -> Circle {
+    Circle {
+        center: Complex::new(-1.0, -1.0 / b),
+        radius: 1.0 / b.abs(),
+    }
+}
+
+/// Constant-VSWR circle, centered on the origin with radius |Γ|.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::smith::vswr_circle;
+/// assert_eq!(vswr_circle(3.0).radius, 0.5);
+/// ```
+#[doc(alias = "VSWR")]
+#[must_use]
+pub fn vswr_circle(vswr: f64) -> Circle {
+    Circle {
+        center: Complex::ZERO,
+        radius: reflection_magnitude_from_vswr(vswr),
+    }
+}
+
+/// Constant noise figure circle of a two-port amplifier.
+///
+/// With N = (F − F_min)/(4r_n) · |1 + Γ_opt|², the circle has center
+/// Γ_opt/(N + 1) and radius √(N(N + 1 − |Γ_opt|²))/(N + 1).
+///
+/// # Arguments
+///
+/// * `nf_min_db` - Minimum noise figure in dB
+/// * `gamma_opt` - Optimum source reflection coefficient
+/// * `rn` - Normalized noise resistance Rn/Z₀
+/// * `nf_db` - Noise figure of the circle in dB (≥ `nf_min_db`)
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::complex::Complex;
+/// use rfconversions::smith::noise_circle;
+/// // At NF = NFmin the circle collapses onto Γopt
+/// let c = noise_circle(1.0, Complex::new(0.3, 0.4), 0.2, 1.0);
+/// assert!((c.center.re - 0.3).abs() < 1e-12 && c.radius.abs() < 1e-12);
+/// ```
+#[doc(alias = "NF circle")]
+#[must_use]
+pub fn noise_circle(nf_min_db: f64, gamma_opt: Complex, rn: f64, nf_db: f64) -> Circle {
+    let f_min = crate::noise::noise_factor_from_noise_figure(nf_min_db);
+    let f = crate::noise::noise_factor_from_noise_figure(nf_db);
+    let n = (f - f_min) / (4.0 * rn) * (Complex::ONE + gamma_opt).norm_sqr();
+
+    Circle {
+        center: gamma_opt.scale(1.0 / (n + 1.0)),
+        radius: (n * (n + 1.0 - gamma_opt.norm_sqr())).sqrt() / (n + 1.0),
+    }
+}
+
+/// An SVG (or ASCII) Smith chart with overlaid sweeps and circles.
+///
+/// Sweeps are given as normalized impedances; circles are in the Γ plane.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::complex::Complex;
+/// use rfconversions::smith::{vswr_circle, SmithChart};
+/// let mut chart = SmithChart::new(400.0);
+/// let sweep = [Complex::new(0.5, -0.5), Complex::ONE, Complex::new(2.0, 0.5)];
+/// chart.add_impedance_sweep(&sweep, "blue");
+/// chart.add_circle(vswr_circle(2.0), "red");
+/// let svg = chart.to_svg();
+/// assert!(svg.starts_with("<svg"));
+/// assert!(svg.contains("stroke=\"blue\""));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SmithChart {
+    size: f64,
+    sweeps: Vec<(Vec<Complex>, String)>,
+    circles: Vec<(Circle, String)>,
+}
+
+/// Grid values drawn for constant-resistance circles and reactance arcs.
+const GRID_VALUES: [f64; 5] = [0.2, 0.5, 1.0, 2.0, 5.0];
+
+/// Escape `&`, `<`, `>` and `"` for use inside a double-quoted SVG attribute.
+fn escape_attribute(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for ch in value.chars() {
+        match ch {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            _ => out.push(ch),
+        }
+    }
+    out
+}
+
+impl SmithChart {
+    /// Create an empty chart `size` pixels square.
+    #[must_use]
+    pub fn new(size: f64) -> Self {
+        SmithChart {
+            size,
+            sweeps: Vec::new(),
+            circles: Vec::new(),
+        }
+    }
+
+    /// Plot a polyline through normalized impedances `points` in `color`.
+    pub fn add_impedance_sweep(&mut self, points: &[Complex], color: &str) {
+        let gammas = points
+            .iter()
+            .map(|&z| impedance_to_reflection_coefficient(z))
+            .collect();
+        self.sweeps.push((gammas, color.to_string()));
+    }
+
+    /// Overlay a Γ-plane circle (VSWR, noise, or grid circle) in `color`.
+    pub fn add_circle(&mut self, circle: Circle, color: &str) {
+        self.circles.push((circle, color.to_string()));
+    }
+
+    /// Map a Γ-plane point to SVG pixel coordinates.
+    fn to_pixel(&self, gamma: Complex) -> (f64, f64) {
+        let half = self.size / 2.0;
+        let radius = half * 0.95;
+        (half + gamma.re * radius, half - gamma.im * radius)
+    }
+
+    fn polyline(&self, out: &mut String, points: &[Complex], style: &str) {
+        let coords: Vec<String> = points
+            .iter()
+            .map(|&p| {
+                let (x, y) = self.to_pixel(p);
+                format!("{x:.2},{y:.2}")
+            })
+            .collect();
+        let _ = writeln!(
+            out,
+            "<polyline points=\"{}\" fill=\"none\" {style}/>",
+            coords.join(" ")
+        );
+    }
+
+    fn circle(&self, out: &mut String, circle: Circle, style: &str) {
+        let (cx, cy) = self.to_pixel(circle.center);
+        let r = circle.radius * self.size / 2.0 * 0.95;
+        let _ = writeln!(
+            out,
+            "<circle cx=\"{cx:.2}\" cy=\"{cy:.2}\" r=\"{r:.2}\" fill=\"none\" {style}/>"
+        );
+    }
+
+    /// Render the chart, including its resistance/reactance grid, as SVG.
+    #[must_use]
+    pub fn to_svg(&self) -> String {
+        let mut out = String::new();
+        let _ = writeln!(
+            out,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{0}\" height=\"{0}\" viewBox=\"0 0 {0} {0}\">",
+            self.size
+        );
+
+        let grid = "stroke=\"#bbb\" stroke-width=\"1\"";
+        self.circle(
+            &mut out,
+            constant_resistance_circle(0.0),
+            "stroke=\"#000\" stroke-width=\"1.5\"",
+        );
+        self.polyline(&mut out, &[-Complex::ONE, Complex::ONE], grid);
+        for r in GRID_VALUES {
+            self.circle(&mut out, constant_resistance_circle(r), grid);
+        }
+        // Reactance arcs, traced from r = 0 to r → ∞ along constant x
+        let resistances: Vec<f64> = (0..=60)
+            .map(|i| 10.0_f64.powf(i as f64 / 10.0 - 3.0) - 1e-3)
+            .collect();
+        for x in GRID_VALUES.iter().flat_map(|&x| [x, -x]) {
+            let arc: Vec<Complex> = resistances
+                .iter()
+                .map(|&r| impedance_to_reflection_coefficient(Complex::new(r, x)))
+                .collect();
+            self.polyline(&mut out, &arc, grid);
+        }
+
+        for (circle, color) in &self.circles {
+            self.circle(
+                &mut out,
+                *circle,
+                &format!(
+                    "stroke=\"{}\" stroke-width=\"1.5\"",
+                    escape_attribute(color)
+                ),
+            );
+        }
+        for (points, color) in &self.sweeps {
+            self.polyline(
+                &mut out,
+                points,
+                &format!("stroke=\"{}\" stroke-width=\"2\"", escape_attribute(color)),
+            );
+        }
+
+        out.push_str("</svg>\n");
+        out
+    }
+
+    /// Render a coarse ASCII plot `columns` × `rows` characters.
+    ///
+    /// The unit circle is drawn with `.`, the real axis with `-`, overlay
+    /// circles with `o`, and sweep points with `*`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `columns` or `rows` is less than 2.
+    #[must_use]
+    pub fn to_ascii(&self, columns: usize, rows: usize) -> String {
+        assert!(
+            columns >= 2 && rows >= 2,
+            "plot needs at least 2 columns and 2 rows"
+        );
+        let mut grid = vec![vec![' '; columns]; rows];
+        let mut plot = |gamma: Complex, ch: char| {
+            let col = ((gamma.re + 1.0) / 2.0 * (columns - 1) as f64).round();
+            let row = ((1.0 - gamma.im) / 2.0 * (rows - 1) as f64).round();
+            if (0.0..columns as f64).contains(&col) && (0.0..rows as f64).contains(&row) {
+                grid[row as usize][col as usize] = ch;
+            }
+        };
+
+        let trace = |circle: Circle| {
+            (0..360).map(move |deg| {
+                circle.center + Complex::from_polar(circle.radius, f64::from(deg).to_radians())
+            })
+        };
+
+        for i in 0..columns {
+            plot(
+                Complex::from(2.0 * i as f64 / (columns - 1) as f64 - 1.0),
+                '-',
+            );
+        }
+        for p in trace(constant_resistance_circle(0.0)) {
+            plot(p, '.');
+        }
+        for (circle, _) in &self.circles {
+            for p in trace(*circle) {
+                plot(p, 'o');
+            }
+        }
+        for (points, _) in &self.sweeps {
+            for &p in points {
+                plot(p, '*');
+            }
+        }
+
+        grid.into_iter()
+            .map(|row| row.into_iter().collect::<String>().trim_end().to_string() + "\n")
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::complex::Complex;
+
+    fn close(a: Complex, b: Complex) -> bool {
+        (a - b).norm() < 1e-12
+    }
+
+    #[test]
+    fn matched_load_is_center() {
+        assert_eq!(
+            super::impedance_to_reflection_coefficient(Complex::ONE),
+            Complex::ZERO
+        );
+    }
+
+    #[test]
+    fn short_and_open() {
+        assert_eq!(
+            super::impedance_to_reflection_coefficient(Complex::ZERO),
+            -Complex::ONE
+        );
+        assert_eq!(
+            super::admittance_to_reflection_coefficient(Complex::ZERO),
+            Complex::ONE
+        );
+    }
+
+    #[test]
+    fn impedance_roundtrip() {
+        for z in [(0.3, 0.7), (2.0, -1.5), (1.0, 0.0), (0.05, 5.0)].map(Complex::from) {
+            let gamma = super::impedance_to_reflection_coefficient(z);
+            assert!(close(super::reflection_coefficient_to_impedance(gamma), z));
+        }
+    }
+
+    #[test]
+    fn admittance_is_inverse_impedance() {
+        let z = Complex::new(0.6, 0.8);
+        let gamma = super::impedance_to_reflection_coefficient(z);
+        let y = super::reflection_coefficient_to_admittance(gamma);
+        // 1 / (0.6 + j0.8) = 0.6 − j0.8
+        assert!(close(y, Complex::new(0.6, -0.8)));
+        assert!(close(super::admittance_to_reflection_coefficient(y), gamma));
+    }
+
+    #[test]
+    fn vswr_reflection_roundtrip() {
+        for s in [1.0, 1.2, 1.5, 2.0, 10.0] {
+            let mag = super::reflection_magnitude_from_vswr(s);
+            assert!((super::vswr_from_reflection_magnitude(mag) - s).abs() < 1e-12);
+        }
+    }
+
+    fn on_circle(c: super::Circle, p: Complex) -> bool {
+        ((p - c.center).norm() - c.radius).abs() < 1e-12
+    }
+
+    #[test]
+    fn constant_resistance_circle_contains_points() {
+        let circle = super::constant_resistance_circle(0.5);
+        for x in [-3.0, -0.2, 0.0, 1.0, 4.0] {
+            assert!(on_circle(
+                circle,
+                super::impedance_to_reflection_coefficient(Complex::new(0.5, x))
+            ));
+        }
+    }
+
+    #[test]
+    fn constant_reactance_circle_contains_points() {
+        let circle = super::constant_reactance_circle(-0.7);
+        for r in [0.0, 0.3, 1.0, 8.0] {
+            assert!(on_circle(
+                circle,
+                super::impedance_to_reflection_coefficient(Complex::new(r, -0.7))
+            ));
+        }
+    }
+
+    #[test]
+    fn admittance_circles_contain_points() {
+        let g_circle = super::constant_conductance_circle(2.0);
+        let b_circle = super::constant_susceptance_circle(0.4);
+        for v in [-1.0, 0.4, 3.0] {
+            assert!(on_circle(
+                g_circle,
+                super::admittance_to_reflection_coefficient(Complex::new(2.0, v))
+            ));
+            assert!(on_circle(
+                b_circle,
+                super::admittance_to_reflection_coefficient(Complex::new(v.abs(), 0.4))
+            ));
+        }
+    }
+
+    #[test]
+    fn vswr_circle_contains_mismatched_load() {
+        // 100 Ω on 50 Ω: VSWR 2
+        let gamma = super::impedance_to_reflection_coefficient(Complex::new(2.0, 0.0));
+        assert!(on_circle(super::vswr_circle(2.0), gamma));
+    }
+
+    #[test]
+    fn noise_circle_grows_with_nf() {
+        let gamma_opt = Complex::new(0.4, 0.3);
+        let small = super::noise_circle(0.8, gamma_opt, 0.15, 1.0);
+        let large = super::noise_circle(0.8, gamma_opt, 0.15, 2.0);
+        assert!(large.radius > small.radius);
+        // Centers move along the line from the origin to Γopt
+        assert!((small.center.im / small.center.re - 0.75).abs() < 1e-12);
+    }
+
+    #[test]
+    fn noise_circle_textbook_value() {
+        // Pozar LNA example: Fmin = 1.6 dB, Γopt = 0.62∠100°, Rn = 20 Ω on 50 Ω, F = 2 dB
+        // → center 0.56∠100°, radius 0.24
+        let gamma_opt = Complex::from_polar(0.62, 100.0_f64.to_radians());
+        let c = super::noise_circle(1.6, gamma_opt, 0.4, 2.0);
+        let center_mag = c.center.norm();
+        assert!((center_mag - 0.56).abs() < 0.01, "center {center_mag}");
+        assert!((c.radius - 0.245).abs() < 0.01, "radius {}", c.radius);
+    }
+
+    #[test]
+    fn svg_contains_grid_and_overlays() {
+        let mut chart = super::SmithChart::new(300.0);
+        chart.add_impedance_sweep(
+            &[
+                Complex::new(1.0, -1.0),
+                Complex::ONE,
+                Complex::new(1.0, 1.0),
+            ],
+            "blue",
+        );
+        chart.add_circle(super::vswr_circle(1.5), "green");
+        let svg = chart.to_svg();
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.trim_end().ends_with("</svg>"));
+        assert!(svg.contains("stroke=\"blue\""));
+        assert!(svg.contains("stroke=\"green\""));
+        // Outer circle + 5 resistance circles + VSWR circle
+        assert_eq!(svg.matches("<circle").count(), 7);
+        // Real axis + 10 reactance arcs + sweep
+        assert_eq!(svg.matches("<polyline").count(), 12);
+    }
+
+    #[test]
+    fn svg_maps_matched_point_to_center() {
+        let mut chart = super::SmithChart::new(200.0);
+        chart.add_impedance_sweep(&[Complex::ONE], "red");
+        assert!(chart
+            .to_svg()
+            .contains("points=\"100.00,100.00\" fill=\"none\" stroke=\"red\""));
+    }
+
+    #[test]
+    fn svg_escapes_colors() {
+        let mut chart = super::SmithChart::new(200.0);
+        chart.add_circle(super::vswr_circle(2.0), "red\" onload=\"x()\"><script>");
+        let svg = chart.to_svg();
+        assert!(svg.contains("stroke=\"red&quot; onload=&quot;x()&quot;&gt;&lt;script&gt;\""));
+        assert!(!svg.contains("<script>"));
+    }
+
+    #[test]
+    fn ascii_plots_center_point() {
+        let mut chart = super::SmithChart::new(200.0);
+        chart.add_impedance_sweep(&[Complex::ONE], "red");
+        let ascii = chart.to_ascii(21, 11);
+        let lines: Vec<&str> = ascii.lines().collect();
+        assert_eq!(lines.len(), 11);
+        assert_eq!(lines[5].chars().nth(10), Some('*'));
+        assert!(lines[0].contains('.'));
+    }
+
+    #[test]
+    #[should_panic(expected = "plot needs at least 2 columns and 2 rows")]
+    fn ascii_rejects_degenerate_size() {
+        let _ = super::SmithChart::new(200.0).to_ascii(0, 11);
+    }
+}
@@ -0,0 +1,321 @@
+use crate::noise::noise_density_dbm_per_hz;
+use crate::power::linear_to_db;
+
+/// Symbol rate (baud) from bit rate, bits per symbol and code rate.
+///
+/// Rₛ = R_b / (k·r)
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::snr::symbol_rate;
+/// // 10 Mb/s QPSK, rate-1/2 code → 10 Mbaud
+/// assert_eq!(symbol_rate(10e6, 2, 0.5), 10e6);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `bits_per_symbol` is zero or `code_rate` is outside (0, 1].
+#[doc(alias = "baud")]
+#[must_use]
+pub fn symbol_rate(bit_rate: f64, bits_per_symbol: u32, code_rate: f64) -> f64 {
+    bit_rate / information_bits_per_symbol(bits_per_symbol, code_rate)
+}
+
+/// Occupied bandwidth (Hz) of a raised-cosine shaped signal.
+///
+/// B = Rₛ·(1 + α)
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::snr::occupied_bandwidth;
+/// let bw = occupied_bandwidth(1e6, 0.35);
+/// assert!((bw - 1.35e6).abs() < 1e-6);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `roll_off` is outside 0–1.
+#[must_use]
+pub fn occupied_bandwidth(symbol_rate: f64, roll_off: f64) -> f64 {
+    check_roll_off(roll_off);
+    symbol_rate * (1.0 + roll_off)
+}
+
+/// Carrier-to-noise-density ratio (dB-Hz) from received power and system
+/// noise temperature.
+///
+/// C/N₀ = C(dBm) − N₀(dBm/Hz)
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::snr::cn0_from_received_power;
+/// // −120 dBm at 290 K → 54 dB-Hz
+/// let cn0 = cn0_from_received_power(-120.0, 290.0);
+/// assert!((cn0 - 54.0).abs() < 0.1);
+/// ```
+#[doc(alias = "C/N0")]
+#[must_use]
+pub fn cn0_from_received_power(power_dbm: f64, noise_temperature: f64) -> f64 {
+    power_dbm - noise_density_dbm_per_hz(noise_temperature)
+}
+
+/// C/N₀ (dB-Hz) from C/N (dB) in a noise bandwidth (Hz).
+///
+/// C/N₀ = C/N + 10·log₁₀(B)
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::snr::cn0_from_cn;
+/// assert!((cn0_from_cn(10.0, 1e6) - 70.0).abs() < 1e-12);
+/// ```
+#[doc(alias = "C/N0")]
+#[must_use]
+pub fn cn0_from_cn(cn_db: f64, noise_bandwidth: f64) -> f64 {
+    cn_db + linear_to_db(noise_bandwidth)
+}
+
+/// C/N (dB) in a noise bandwidth (Hz) from C/N₀ (dB-Hz).
+///
+/// C/N = C/N₀ − 10·log₁₀(B)
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::snr::cn_from_cn0;
+/// assert!((cn_from_cn0(70.0, 1e6) - 10.0).abs() < 1e-12);
+/// ```
+#[doc(alias = "CNR")]
+#[must_use]
+pub fn cn_from_cn0(cn0_db_hz: f64, noise_bandwidth: f64) -> f64 {
+    cn0_db_hz - linear_to_db(noise_bandwidth)
+}
+
+/// Eb/N₀ (dB) from C/N₀ (dB-Hz) and information bit rate (b/s).
+///
+/// Eb/N₀ = C/N₀ − 10·log₁₀(R_b)
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::snr::ebn0_from_cn0;
+/// assert!((ebn0_from_cn0(70.0, 1e6) - 10.0).abs() < 1e-12);
+/// ```
+#[doc(alias = "Eb/N0")]
+#[must_use]
+pub fn ebn0_from_cn0(cn0_db_hz: f64, bit_rate: f64) -> f64 {
+    cn0_db_hz - linear_to_db(bit_rate)
+}
+
+/// C/N₀ (dB-Hz) required for an Eb/N₀ (dB) at an information bit rate.
+///
+/// C/N₀ = Eb/N₀ + 10·log₁₀(R_b)
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::snr::cn0_from_ebn0;
+/// assert!((cn0_from_ebn0(10.0, 1e6) - 70.0).abs() < 1e-12);
+/// ```
+#[doc(alias = "C/N0")]
+#[must_use]
+pub fn cn0_from_ebn0(ebn0_db: f64, bit_rate: f64) -> f64 {
+    ebn0_db + linear_to_db(bit_rate)
+}
+
+/// Es/N₀ (dB) from C/N₀ (dB-Hz) and symbol rate (baud).
+///
+/// Es/N₀ = C/N₀ − 10·log₁₀(Rₛ)
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::snr::esn0_from_cn0;
+/// assert!((esn0_from_cn0(70.0, 1e5) - 20.0).abs() < 1e-12);
+/// ```
+#[doc(alias = "Es/N0")]
+#[must_use]
+pub fn esn0_from_cn0(cn0_db_hz: f64, symbol_rate: f64) -> f64 {
+    cn0_db_hz - linear_to_db(symbol_rate)
+}
+
+/// Es/N₀ (dB) from Eb/N₀ (dB), bits per symbol and code rate.
+///
+/// Es/N₀ = Eb/N₀ + 10·log₁₀(k·r)
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::snr::esn0_from_ebn0;
+/// // 16-QAM, uncoded: +6.02 dB
+/// assert!((esn0_from_ebn0(10.0, 4, 1.0) - 16.0206).abs() < 1e-4);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `bits_per_symbol` is zero or `code_rate` is outside (0, 1].
+#[doc(alias = "Es/N0")]
+#[must_use]
+pub fn esn0_from_ebn0(ebn0_db: f64, bits_per_symbol: u32, code_rate: f64) -> f64 {
+    ebn0_db + linear_to_db(information_bits_per_symbol(bits_per_symbol, code_rate))
+}
+
+/// Eb/N₀ (dB) from Es/N₀ (dB), bits per symbol and code rate.
+///
+/// Eb/N₀ = Es/N₀ − 10·log₁₀(k·r)
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::snr::ebn0_from_esn0;
+/// // QPSK with a rate-3/4 code: 1.5 information bits per symbol
+/// assert!((ebn0_from_esn0(10.0, 2, 0.75) - 8.2391).abs() < 1e-4);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `bits_per_symbol` is zero or `code_rate` is outside (0, 1].
+#[doc(alias = "Eb/N0")]
+#[must_use]
+pub fn ebn0_from_esn0(esn0_db: f64, bits_per_symbol: u32, code_rate: f64) -> f64 {
+    esn0_db - linear_to_db(information_bits_per_symbol(bits_per_symbol, code_rate))
+}
+
+/// Eb/N₀ (dB) from SNR (dB) measured in a noise bandwidth (Hz).
+///
+/// Eb/N₀ = SNR + 10·log₁₀(B / R_b)
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::snr::ebn0_from_snr;
+/// // SNR of 10 dB in 2 MHz at 1 Mb/s
+/// assert!((ebn0_from_snr(10.0, 2e6, 1e6) - 13.0103).abs() < 1e-4);
+/// ```
+#[doc(alias = "Eb/N0")]
+#[must_use]
+pub fn ebn0_from_snr(snr_db: f64, noise_bandwidth: f64, bit_rate: f64) -> f64 {
+    snr_db + linear_to_db(noise_bandwidth / bit_rate)
+}
+
+/// SNR (dB) in a noise bandwidth (Hz) from Eb/N₀ (dB).
+///
+/// SNR = Eb/N₀ − 10·log₁₀(B / R_b)
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::snr::snr_from_ebn0;
+/// assert!((snr_from_ebn0(13.0103, 2e6, 1e6) - 10.0).abs() < 1e-4);
+/// ```
+#[doc(alias = "SNR")]
+#[must_use]
+pub fn snr_from_ebn0(ebn0_db: f64, noise_bandwidth: f64, bit_rate: f64) -> f64 {
+    ebn0_db - linear_to_db(noise_bandwidth / bit_rate)
+}
+
+/// SNR (dB) in the occupied bandwidth Rₛ·(1 + α) from Es/N₀ (dB).
+///
+/// SNR = Es/N₀ − 10·log₁₀(1 + α)
+///
+/// A matched root-raised-cosine receiver has a noise bandwidth of Rₛ, so its
+/// post-filter SNR equals Es/N₀; this conversion is for noise measured across
+/// the full occupied bandwidth, e.g. on a spectrum analyzer.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::snr::snr_from_esn0;
+/// assert!((snr_from_esn0(15.0, 0.25) - 14.0309).abs() < 1e-4);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `roll_off` is outside 0–1.
+#[doc(alias = "SNR")]
+#[must_use]
+pub fn snr_from_esn0(esn0_db: f64, roll_off: f64) -> f64 {
+    check_roll_off(roll_off);
+    esn0_db - linear_to_db(1.0 + roll_off)
+}
+
+/// Es/N₀ (dB) from SNR (dB) in the occupied bandwidth Rₛ·(1 + α).
+///
+/// Es/N₀ = SNR + 10·log₁₀(1 + α)
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::snr::esn0_from_snr;
+/// assert!((esn0_from_snr(14.0309, 0.25) - 15.0).abs() < 1e-4);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `roll_off` is outside 0–1.
+#[doc(alias = "Es/N0")]
+#[must_use]
+pub fn esn0_from_snr(snr_db: f64, roll_off: f64) -> f64 {
+    check_roll_off(roll_off);
+    snr_db + linear_to_db(1.0 + roll_off)
+}
+
+fn information_bits_per_symbol(bits_per_symbol: u32, code_rate: f64) -> f64 {
+    assert!(bits_per_symbol >= 1, "bits per symbol must be at least 1");
+    assert!(
+        code_rate > 0.0 && code_rate <= 1.0,
+        "code rate must be in (0, 1]"
+    );
+    bits_per_symbol as f64 * code_rate
+}
+
+fn check_roll_off(roll_off: f64) {
+    assert!(
+        (0.0..=1.0).contains(&roll_off),
+        "roll-off must be between 0 and 1"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn link_chain_round_trip() {
+        // Received power → C/N₀ → Eb/N₀ → Es/N₀ → SNR and back to C/N₀.
+        let cn0 = super::cn0_from_received_power(-110.0, 500.0);
+        let rb = 2e6;
+        let ebn0 = super::ebn0_from_cn0(cn0, rb);
+        let esn0 = super::esn0_from_ebn0(ebn0, 2, 0.5);
+        let rs = super::symbol_rate(rb, 2, 0.5);
+        assert!((esn0 - super::esn0_from_cn0(cn0, rs)).abs() < 1e-12);
+
+        let bw = super::occupied_bandwidth(rs, 0.35);
+        let snr = super::snr_from_esn0(esn0, 0.35);
+        assert!((super::cn0_from_cn(snr, bw) - cn0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn snr_equals_ebn0_when_bandwidth_equals_bit_rate() {
+        assert_eq!(super::ebn0_from_snr(7.0, 1e6, 1e6), 7.0);
+        assert_eq!(super::snr_from_ebn0(7.0, 1e6, 1e6), 7.0);
+    }
+
+    #[test]
+    fn bpsk_es_equals_eb() {
+        assert_eq!(super::esn0_from_ebn0(9.6, 1, 1.0), 9.6);
+    }
+
+    #[test]
+    #[should_panic(expected = "code rate must be in (0, 1]")]
+    fn rejects_code_rate_above_one() {
+        let _ = super::symbol_rate(1e6, 2, 1.5);
+    }
+
+    #[test]
+    #[should_panic(expected = "bits per symbol must be at least 1")]
+    fn rejects_zero_bits_per_symbol() {
+        let _ = super::ebn0_from_esn0(10.0, 0, 1.0);
+    }
+}
@@ -0,0 +1,238 @@
+/// A mixing product |m·RF + n·LO| swept over an RF band.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Spur {
+    /// RF harmonic order m (0 for pure LO harmonics).
+    pub rf_order: u32,
+    /// Signed LO harmonic order n; negative for m·RF − |n|·LO.
+    pub lo_order: i32,
+    /// Lowest output frequency in Hz over the RF band.
+    pub low: f64,
+    /// Highest output frequency in Hz over the RF band.
+    pub high: f64,
+    /// Estimated level relative to the desired 1×1 product in dBc, or
+    /// `None` when the intercept model has no estimate: LO-only products
+    /// and 1×n products, whose level is set by LO harmonic content and
+    /// port isolation rather than the RF drive.
+    pub level_dbc: Option<f64>,
+}
+
+impl Spur {
+    /// Mixing order m + |n|.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfconversions::spur::Spur;
+    /// let s = Spur { rf_order: 2, lo_order: -3, low: 1e9, high: 1e9, level_dbc: None };
+    /// assert_eq!(s.order(), 5);
+    /// ```
+    #[must_use]
+    pub fn order(&self) -> u32 {
+        self.rf_order + self.lo_order.unsigned_abs()
+    }
+
+    /// Whether this is the wanted 1×1 conversion product.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfconversions::spur::Spur;
+    /// let s = Spur { rf_order: 1, lo_order: -1, low: 1e9, high: 1e9, level_dbc: Some(0.0) };
+    /// assert!(s.is_desired());
+    /// ```
+    #[must_use]
+    pub fn is_desired(&self) -> bool {
+        self.rf_order == 1 && self.lo_order.abs() == 1
+    }
+
+    /// Whether any part of the product falls inside a `(low, high)` band in
+    /// Hz.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfconversions::spur::Spur;
+    /// let s = Spur { rf_order: 2, lo_order: -2, low: 380e6, high: 420e6, level_dbc: None };
+    /// assert!(s.overlaps((400e6, 500e6)));
+    /// assert!(!s.overlaps((450e6, 500e6)));
+    /// ```
+    #[must_use]
+    pub fn overlaps(&self, band: (f64, f64)) -> bool {
+        self.low <= band.1 && self.high >= band.0
+    }
+}
+
+/// Estimated level (dBc) of an m-th order RF product relative to the
+/// desired output, from the RF drive and a single intercept point
+/// characterising the mixer.
+///
+/// dBc = −(m − 1)·(IP − P_RF)
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::spur::spur_level_dbc;
+/// // −10 dBm RF into a mixer with a +20 dBm intercept: 2×n spurs at −30 dBc
+/// assert_eq!(spur_level_dbc(2, -10.0, 20.0), -30.0);
+/// ```
+#[must_use]
+pub fn spur_level_dbc(rf_order: u32, rf_dbm: f64, intercept_dbm: f64) -> f64 {
+    -(rf_order.saturating_sub(1) as f64) * (intercept_dbm - rf_dbm)
+}
+
+/// All mixing products |m·RF + n·LO| with m + |n| ≤ `max_order` for an RF
+/// band `(low, high)` in Hz, including RF and LO harmonics, sorted by order.
+/// Single tones are zero-width bands.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::spur::mixing_products;
+/// let products = mixing_products((2.4e9, 2.4e9), 2.0e9, 2);
+/// // RF, LO, 2·RF, 2·LO, RF ± LO
+/// assert_eq!(products.len(), 6);
+/// assert!(products.iter().any(|s| s.is_desired() && s.low == 400e6));
+/// ```
+///
+/// # Panics
+///
+/// Panics if the band is reversed or a frequency is negative.
+#[must_use]
+pub fn mixing_products(rf_band: (f64, f64), lo: f64, max_order: u32) -> Vec<Spur> {
+    assert!(
+        rf_band.0 >= 0.0 && lo >= 0.0,
+        "frequencies must not be negative"
+    );
+    assert!(rf_band.1 >= rf_band.0, "band must be (low, high)");
+
+    let max = max_order as i32;
+    let mut spurs: Vec<Spur> = (0..=max)
+        .flat_map(|m| {
+            let span = max - m;
+            (-span..=span)
+                .filter(move |&n| (m, n) != (0, 0) && (m > 0 || n > 0))
+                .map(move |n| (m, n))
+        })
+        .map(|(m, n)| {
+            let a = m as f64 * rf_band.0 + n as f64 * lo;
+            let b = m as f64 * rf_band.1 + n as f64 * lo;
+            let (low, high) = if a.signum() != b.signum() && a != 0.0 && b != 0.0 {
+                (0.0, a.abs().max(b.abs()))
+            } else {
+                (a.abs().min(b.abs()), a.abs().max(b.abs()))
+            };
+            Spur {
+                rf_order: m as u32,
+                lo_order: n,
+                low,
+                high,
+                level_dbc: None,
+            }
+        })
+        .collect();
+    spurs.sort_by_key(|s| (s.order(), s.rf_order, s.lo_order));
+    spurs
+}
+
+/// Unwanted mixing products up to `max_order` that land in a `(low, high)`
+/// IF or receive band, with levels estimated by [`spur_level_dbc`] for
+/// RF orders of two and above.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::spur::spurs_in_band;
+/// // 1.0–1.1 GHz RF at −20 dBm, 900 MHz LO, 100–250 MHz IF, +15 dBm intercept
+/// let spurs = spurs_in_band((1.0e9, 1.1e9), 0.9e9, 5, (100e6, 250e6), -20.0, 15.0);
+/// // 2·RF − 2·LO sweeps 200–400 MHz through the top of the IF
+/// let s = spurs.iter().find(|s| s.rf_order == 2 && s.lo_order == -2).unwrap();
+/// assert_eq!(s.level_dbc, Some(-35.0));
+/// ```
+///
+/// # Panics
+///
+/// Panics if a band is reversed or a frequency is negative.
+#[must_use]
+pub fn spurs_in_band(
+    rf_band: (f64, f64),
+    lo: f64,
+    max_order: u32,
+    band: (f64, f64),
+    rf_dbm: f64,
+    intercept_dbm: f64,
+) -> Vec<Spur> {
+    assert!(band.1 >= band.0, "band must be (low, high)");
+    mixing_products(rf_band, lo, max_order)
+        .into_iter()
+        .filter(|s| !s.is_desired() && s.overlaps(band))
+        .map(|s| Spur {
+            level_dbc: (s.rf_order > 1).then(|| spur_level_dbc(s.rf_order, rf_dbm, intercept_dbm)),
+            ..s
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn product_count_matches_order() {
+        // N pure-LO terms plus Σ (2(N − m) + 1) = N² mixed terms
+        for order in 1..=7_u32 {
+            let n = super::mixing_products((1e9, 1e9), 1.3e9, order).len();
+            assert_eq!(n as u32, order * order + order);
+        }
+    }
+
+    #[test]
+    fn band_straddling_zero_reaches_dc() {
+        // RF 0.9–1.1 GHz against a 1 GHz LO: RF − LO spans −100…+100 MHz
+        let products = super::mixing_products((0.9e9, 1.1e9), 1e9, 2);
+        let diff = products
+            .iter()
+            .find(|s| s.rf_order == 1 && s.lo_order == -1)
+            .unwrap();
+        assert_eq!(diff.low, 0.0);
+        assert!((diff.high - 100e6).abs() < 1e-3);
+    }
+
+    #[test]
+    fn half_if_spur_is_found() {
+        // Low-side LO: RF 1.1 GHz, LO 1.0 GHz, IF 100 MHz. An RF input at
+        // LO + IF/2 = 1.05 GHz gives 2·RF − 2·LO = IF.
+        let spurs = super::spurs_in_band((1.05e9, 1.05e9), 1e9, 4, (99e6, 101e6), -30.0, 10.0);
+        let half_if = spurs
+            .iter()
+            .find(|s| s.rf_order == 2 && s.lo_order == -2)
+            .unwrap();
+        assert_eq!(half_if.level_dbc, Some(-40.0));
+    }
+
+    #[test]
+    fn lo_harmonics_have_no_level() {
+        let spurs = super::spurs_in_band((1e9, 1e9), 300e6, 3, (850e6, 950e6), -10.0, 20.0);
+        let lo3 = spurs
+            .iter()
+            .find(|s| s.rf_order == 0 && s.lo_order == 3)
+            .unwrap();
+        assert_eq!(lo3.level_dbc, None);
+    }
+
+    #[test]
+    fn first_order_rf_products_have_no_level() {
+        // RF feedthrough (1×0) and 1×2 at 3.2 GHz − 2·1.0 GHz = 1.2 GHz
+        let spurs = super::spurs_in_band((1.2e9, 3.2e9), 1e9, 3, (1.15e9, 1.25e9), -10.0, 20.0);
+        for n in [0, -2] {
+            let s = spurs
+                .iter()
+                .find(|s| s.rf_order == 1 && s.lo_order == n)
+                .unwrap();
+            assert_eq!(s.level_dbc, None);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "band must be (low, high)")]
+    fn rejects_reversed_band() {
+        let _ = super::mixing_products((2e9, 1e9), 1e9, 3);
+    }
+}
@@ -0,0 +1,346 @@
+use crate::filter::{insertion_loss_db, FilterResponse, Prototype};
+
+/// Points per band used when evaluating image rejection.
+const REJECTION_SAMPLES: usize = 64;
+
+/// LO injection side.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Injection {
+    /// LO below the RF: IF = RF − LO.
+    LowSide,
+    /// LO above the RF: IF = LO − RF, spectrum inverted.
+    HighSide,
+}
+
+/// One frequency conversion with a fixed LO. Bands are `(low, high)` in Hz.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Conversion {
+    /// LO injection side.
+    pub injection: Injection,
+    /// Input (RF) band.
+    pub input_band: (f64, f64),
+    /// LO frequency.
+    pub lo: f64,
+    /// Output (IF) band.
+    pub output_band: (f64, f64),
+    /// Image band, converted to the same IF as the input band.
+    pub image_band: (f64, f64),
+    /// Input band whose 2×2 product lands in the IF (half-IF spur).
+    pub half_if_band: (f64, f64),
+}
+
+impl Conversion {
+    /// Whether the output spectrum is inverted (high-side injection).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfconversions::superhet::{convert, Injection};
+    /// assert!(convert((2.4e9, 2.5e9), 300e6, Injection::HighSide).is_inverted());
+    /// ```
+    #[must_use]
+    pub fn is_inverted(&self) -> bool {
+        self.injection == Injection::HighSide
+    }
+
+    /// Gap (Hz) between the input band and its image, which a preselector
+    /// must transition across: 2·IF − bandwidth.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfconversions::superhet::{convert, Injection};
+    /// let c = convert((2.4e9, 2.5e9), 140e6, Injection::LowSide);
+    /// assert!((c.image_guard_band() - 180e6).abs() < 1e-3);
+    /// ```
+    #[must_use]
+    pub fn image_guard_band(&self) -> f64 {
+        if self.image_band.0 > self.input_band.1 {
+            self.image_band.0 - self.input_band.1
+        } else {
+            self.input_band.0 - self.image_band.1
+        }
+    }
+
+    /// Image rejection (dB) of a preselector: its smallest attenuation over
+    /// the image band less its largest loss over the input band.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfconversions::filter::{prototype, Approximation, FilterResponse};
+    /// use rfconversions::superhet::{convert, Injection};
+    /// let stage = convert((2.4e9, 2.5e9), 400e6, Injection::HighSide);
+    /// let preselector = prototype(Approximation::Chebyshev { ripple_db: 0.1 }, 5).unwrap();
+    /// let response = FilterResponse::Bandpass { center: 2.449e9, bandwidth: 150e6 };
+    /// assert!(stage.image_rejection_db(&preselector, response) > 60.0);
+    /// ```
+    #[must_use]
+    pub fn image_rejection_db(&self, prototype: &Prototype, response: FilterResponse) -> f64 {
+        let worst_image = sample(self.image_band)
+            .map(|f| insertion_loss_db(prototype, response, f))
+            .fold(f64::INFINITY, f64::min);
+        let worst_passband = sample(self.input_band)
+            .map(|f| insertion_loss_db(prototype, response, f))
+            .fold(f64::NEG_INFINITY, f64::max);
+        worst_image - worst_passband
+    }
+
+    /// Whether a preselector meets an image rejection requirement (dB).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfconversions::filter::{prototype, Approximation, FilterResponse};
+    /// use rfconversions::superhet::{convert, Injection};
+    /// let stage = convert((2.4e9, 2.5e9), 140e6, Injection::LowSide);
+    /// let preselector = prototype(Approximation::Butterworth, 3).unwrap();
+    /// let response = FilterResponse::Bandpass { center: 2.45e9, bandwidth: 150e6 };
+    /// assert!(!stage.meets_image_rejection(&preselector, response, 40.0));
+    /// ```
+    #[must_use]
+    pub fn meets_image_rejection(
+        &self,
+        prototype: &Prototype,
+        response: FilterResponse,
+        required_db: f64,
+    ) -> bool {
+        self.image_rejection_db(prototype, response) >= required_db
+    }
+}
+
+/// LO frequency (Hz) that converts an RF frequency to an IF.
+///
+/// Low side: LO = RF − IF; high side: LO = RF + IF
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::superhet::{lo_frequency, Injection};
+/// assert_eq!(lo_frequency(900e6, 70e6, Injection::LowSide), 830e6);
+/// assert_eq!(lo_frequency(900e6, 70e6, Injection::HighSide), 970e6);
+/// ```
+///
+/// # Panics
+///
+/// Panics if a low-side IF is not below the RF.
+#[must_use]
+pub fn lo_frequency(rf: f64, if_frequency: f64, injection: Injection) -> f64 {
+    match injection {
+        Injection::LowSide => {
+            assert!(
+                if_frequency < rf,
+                "low-side injection needs the IF below the RF"
+            );
+            rf - if_frequency
+        }
+        Injection::HighSide => rf + if_frequency,
+    }
+}
+
+/// IF (Hz) produced by an RF and LO.
+///
+/// IF = |RF − LO|
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::superhet::if_frequency;
+/// assert_eq!(if_frequency(900e6, 970e6), 70e6);
+/// ```
+#[doc(alias = "intermediate frequency")]
+#[must_use]
+pub fn if_frequency(rf: f64, lo: f64) -> f64 {
+    (rf - lo).abs()
+}
+
+/// Image frequency (Hz): the other input that converts to the same IF.
+///
+/// f_image = 2·LO − RF
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::superhet::image_frequency;
+/// assert_eq!(image_frequency(900e6, 830e6), 760e6);
+/// ```
+#[must_use]
+pub fn image_frequency(rf: f64, lo: f64) -> f64 {
+    (2.0 * lo - rf).abs()
+}
+
+/// Input frequency (Hz) whose 2×2 product falls at the IF, half an IF from
+/// the LO towards the RF.
+///
+/// f = (RF + LO) / 2
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::superhet::half_if_frequency;
+/// assert_eq!(half_if_frequency(900e6, 830e6), 865e6);
+/// ```
+#[doc(alias = "half-IF")]
+#[must_use]
+pub fn half_if_frequency(rf: f64, lo: f64) -> f64 {
+    0.5 * (rf + lo)
+}
+
+/// Plan a fixed-LO conversion of an input band `(low, high)` in Hz so that
+/// its center lands at `if_center`.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::superhet::{convert, Injection};
+/// let c = convert((2.4e9, 2.5e9), 400e6, Injection::LowSide);
+/// assert_eq!(c.lo, 2.05e9);
+/// assert_eq!(c.output_band, (350e6, 450e6));
+/// assert_eq!(c.image_band, (1.6e9, 1.7e9));
+/// ```
+///
+/// # Panics
+///
+/// Panics if the band is reversed or the IF does not exceed half the
+/// input bandwidth, which would fold the output band through DC and put the
+/// image inside the input band.
+#[must_use]
+pub fn convert(input_band: (f64, f64), if_center: f64, injection: Injection) -> Conversion {
+    assert!(input_band.1 >= input_band.0, "band must be (low, high)");
+    let half_width = 0.5 * (input_band.1 - input_band.0);
+    assert!(
+        if_center > half_width,
+        "IF must exceed half the input bandwidth"
+    );
+    let center = 0.5 * (input_band.0 + input_band.1);
+    let lo = lo_frequency(center, if_center, injection);
+    let map = |f: fn(f64, f64) -> f64| {
+        let (a, b) = (f(input_band.0, lo), f(input_band.1, lo));
+        (a.min(b), a.max(b))
+    };
+    Conversion {
+        injection,
+        input_band,
+        lo,
+        output_band: map(if_frequency),
+        image_band: map(image_frequency),
+        half_if_band: map(half_if_frequency),
+    }
+}
+
+/// Choose the injection side for a conversion whose image band hits the
+/// fewest of the given interferer bands; ties go to high-side injection,
+/// which needs the smaller fractional LO range.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::superhet::{select_injection, Injection};
+/// // A strong emitter at 1.6–1.7 GHz rules out the low-side image
+/// let side = select_injection((2.4e9, 2.5e9), 400e6, &[(1.6e9, 1.7e9)]);
+/// assert_eq!(side, Injection::HighSide);
+/// // ...and one at 3.2–3.3 GHz rules out the high-side image
+/// let side = select_injection((2.4e9, 2.5e9), 400e6, &[(3.2e9, 3.3e9)]);
+/// assert_eq!(side, Injection::LowSide);
+/// ```
+///
+/// # Panics
+///
+/// Panics under the same conditions as [`convert`].
+#[must_use]
+pub fn select_injection(
+    input_band: (f64, f64),
+    if_center: f64,
+    interferers: &[(f64, f64)],
+) -> Injection {
+    let hits = |injection: Injection| {
+        let image = convert(input_band, if_center, injection).image_band;
+        interferers
+            .iter()
+            .filter(|&&(low, high)| low <= image.1 && high >= image.0)
+            .count()
+    };
+    let low_side_possible = if_center < 0.5 * (input_band.0 + input_band.1);
+    if low_side_possible && hits(Injection::LowSide) < hits(Injection::HighSide) {
+        Injection::LowSide
+    } else {
+        Injection::HighSide
+    }
+}
+
+/// Plan a multi-conversion chain: each `(if_center, injection)` stage
+/// converts the previous stage's output band.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::superhet::{plan_chain, Injection};
+/// // 10.7–10.8 GHz → 1.2 GHz → 140 MHz
+/// let chain = plan_chain((10.7e9, 10.8e9), &[(1.2e9, Injection::LowSide), (140e6, Injection::HighSide)]);
+/// assert_eq!(chain[0].lo, 9.55e9);
+/// assert_eq!(chain[1].input_band, chain[0].output_band);
+/// assert_eq!(chain[1].output_band, (90e6, 190e6));
+/// assert!(chain.iter().all(|c| c.image_guard_band() > 0.0));
+/// ```
+///
+/// # Panics
+///
+/// Panics under the same conditions as [`convert`] for any stage.
+#[must_use]
+pub fn plan_chain(rf_band: (f64, f64), stages: &[(f64, Injection)]) -> Vec<Conversion> {
+    let mut band = rf_band;
+    stages
+        .iter()
+        .map(|&(if_center, injection)| {
+            let conversion = convert(band, if_center, injection);
+            band = conversion.output_band;
+            conversion
+        })
+        .collect()
+}
+
+/// Evenly spaced frequencies across a band, inclusive.
+fn sample(band: (f64, f64)) -> impl Iterator<Item = f64> {
+    let step = (band.1 - band.0) / (REJECTION_SAMPLES - 1) as f64;
+    (0..REJECTION_SAMPLES).map(move |i| band.0 + i as f64 * step)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Injection;
+
+    #[test]
+    fn image_converts_to_the_same_if() {
+        let (rf, lo) = (1.9e9, 2.1e9);
+        let image = super::image_frequency(rf, lo);
+        assert_eq!(super::if_frequency(image, lo), super::if_frequency(rf, lo));
+    }
+
+    #[test]
+    fn half_if_doubles_to_the_if() {
+        let (rf, lo) = (1.9e9, 2.1e9);
+        let half = super::half_if_frequency(rf, lo);
+        assert!(((2.0 * (half - lo)).abs() - super::if_frequency(rf, lo)).abs() < 1e-3);
+    }
+
+    #[test]
+    fn high_side_inverts_the_band() {
+        let c = super::convert((1.0e9, 1.1e9), 300e6, Injection::HighSide);
+        assert_eq!(c.lo, 1.35e9);
+        assert_eq!(c.output_band, (250e6, 350e6));
+        assert_eq!(c.image_band, (1.6e9, 1.7e9));
+        assert_eq!(c.half_if_band, (1.175e9, 1.225e9));
+    }
+
+    #[test]
+    fn select_falls_back_to_high_side_when_low_side_impossible() {
+        let side = super::select_injection((100e6, 120e6), 200e6, &[(500e6, 600e6)]);
+        assert_eq!(side, Injection::HighSide);
+    }
+
+    #[test]
+    #[should_panic(expected = "IF must exceed half the input bandwidth")]
+    fn rejects_if_inside_band() {
+        let _ = super::convert((1e9, 1.2e9), 50e6, Injection::LowSide);
+    }
+}
@@ -0,0 +1,566 @@
+use std::f64::consts::PI;
+
+/// Conversion factor from nepers to decibels (20 / ln 10).
+const NEPER_TO_DB: f64 = 8.685_889_638_065_035;
+
+/// Meters per inch, used to express the WR-xx table in SI units.
+const INCH: f64 = 0.0254;
+
+/// Propagating mode family of a waveguide.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModeType {
+    /// Transverse electric (no longitudinal E field).
+    Te,
+    /// Transverse magnetic (no longitudinal H field).
+    Tm,
+}
+
+/// Zeros of the Bessel function Jₙ, indexed `[n][m - 1]` (TM modes).
+const BESSEL_ZEROS: [[f64; 3]; 4] = [
+    [2.404825557695773, 5.520078110286311, 8.653727912911012],
+    [3.831705970207512, 7.015586669815619, 10.17346813506272],
+    [5.135622301840683, 8.417244140399865, 11.61984117214906],
+    [6.380161895923984, 9.76102312998167, 13.01520072169843],
+];
+
+/// Zeros of the Bessel derivative Jₙ′, indexed `[n][m - 1]` (TE modes).
+const BESSEL_DERIVATIVE_ZEROS: [[f64; 3]; 4] = [
+    [3.831705970207512, 7.015586669815619, 10.17346813506272],
+    [1.841183781340659, 5.331442773525033, 8.536316366346285],
+    [3.05423692822714, 6.706133194158459, 9.969467823087596],
+    [4.201188941210528, 8.015236598375951, 11.3459243107426],
+];
+
+/// Cutoff frequency (Hz) of the TEₘₙ or TMₘₙ mode of a rectangular waveguide.
+///
+/// f_c = (c / 2) · √((m/a)² + (n/b)²)
+///
+/// # Arguments
+///
+/// * `width` - Broad wall dimension `a` in meters
+/// * `height` - Narrow wall dimension `b` in meters
+/// * `mode` - TE or TM
+/// * `m`, `n` - Mode indices along the broad and narrow walls
+///
+/// # Panics
+///
+/// Panics if `m` and `n` are both zero, or if either is zero for a TM mode.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::waveguide::{rectangular_cutoff_frequency, ModeType};
+/// // WR-90 (0.9" × 0.4"): TE10 cutoff ≈ 6.557 GHz
+/// let fc = rectangular_cutoff_frequency(0.02286, 0.01016, ModeType::Te, 1, 0);
+/// assert!((fc - 6.557e9).abs() < 1e6);
+/// ```
+#[doc(alias = "fc")]
+#[doc(alias = "TE10")]
+#[must_use]
+pub fn rectangular_cutoff_frequency(
+    width: f64,
+    height: f64,
+    mode: ModeType,
+    m: u32,
+    n: u32,
+) -> f64 {
+    match mode {
+        ModeType::Te => assert!(m + n > 0, "TE00 does not exist"),
+        ModeType::Tm => assert!(m > 0 && n > 0, "TM modes require m >= 1 and n >= 1"),
+    }
+
+    let kx = f64::from(m) / width;
+    let ky = f64::from(n) / height;
+    crate::constants::SPEED_OF_LIGHT / 2.0 * (kx * kx + ky * ky).sqrt()
+}
+
+/// Cutoff frequency (Hz) of the TEₙₘ or TMₙₘ mode of a circular waveguide.
+///
+/// f_c = p · c / (2π·r), where `p` is the m-th zero of Jₙ (TM) or Jₙ′ (TE).
+///
+/// # Arguments
+///
+/// * `radius` - Inner radius in meters
+/// * `mode` - TE or TM
+/// * `n` - Azimuthal index (0–3)
+/// * `m` - Radial index (1–3)
+///
+/// # Panics
+///
+/// Panics if `n` > 3 or `m` is outside 1–3.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::waveguide::{circular_cutoff_frequency, ModeType};
+/// // 10 mm radius: dominant TE11 cutoff ≈ 8.79 GHz
+/// let fc = circular_cutoff_frequency(0.01, ModeType::Te, 1, 1);
+/// assert!((fc - 8.785e9).abs() < 1e7);
+/// ```
+#[doc(alias = "fc")]
+#[doc(alias = "TE11")]
+#[must_use]
+pub fn circular_cutoff_frequency(radius: f64, mode: ModeType, n: u32, m: u32) -> f64 {
+    assert!(n <= 3, "azimuthal index n must be 0..=3");
+    assert!((1..=3).contains(&m), "radial index m must be 1..=3");
+
+    let table = match mode {
+        ModeType::Te => &BESSEL_DERIVATIVE_ZEROS,
+        ModeType::Tm => &BESSEL_ZEROS,
+    };
+    let p = table[n as usize][(m - 1) as usize];
+    p * crate::constants::SPEED_OF_LIGHT / (2.0 * PI * radius)
+}
+
+/// Dispersion factor √(1 − (f_c/f)²) shared by the propagation formulas.
+fn dispersion_factor(frequency: f64, cutoff_frequency: f64) -> f64 {
+    let ratio = cutoff_frequency / frequency;
+    (1.0 - ratio * ratio).sqrt()
+}
+
+/// Guide wavelength (meters) of a mode above cutoff.
+///
+/// λg = λ₀ / √(1 − (f_c/f)²)
+///
+/// Below cutoff the mode is evanescent and the result is NaN.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::waveguide::guide_wavelength;
+/// // WR-90 at 10 GHz (fc = 6.557 GHz): λg ≈ 39.7 mm
+/// let lg = guide_wavelength(10.0e9, 6.557e9);
+/// assert!((lg - 0.0397).abs() < 0.0001);
+/// ```
+#[doc(alias = "lambda_g")]
+#[must_use]
+pub fn guide_wavelength(frequency: f64, cutoff_frequency: f64) -> f64 {
+    crate::frequency::frequency_to_wavelength(frequency)
+        / dispersion_factor(frequency, cutoff_frequency)
+}
+
+/// Phase velocity (m/s) of a mode above cutoff.
+///
+/// v_p = c / √(1 − (f_c/f)²), always faster than light.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::waveguide::phase_velocity;
+/// let vp = phase_velocity(10.0e9, 6.557e9);
+/// assert!(vp > rfconversions::constants::SPEED_OF_LIGHT);
+/// ```
+#[doc(alias = "vp")]
+#[must_use]
+pub fn phase_velocity(frequency: f64, cutoff_frequency: f64) -> f64 {
+    crate::constants::SPEED_OF_LIGHT / dispersion_factor(frequency, cutoff_frequency)
+}
+
+/// Group velocity (m/s) of a mode above cutoff.
+///
+/// v_g = c · √(1 − (f_c/f)²), so that v_p · v_g = c².
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::waveguide::group_velocity;
+/// let vg = group_velocity(10.0e9, 6.557e9);
+/// assert!((vg - 2.2634e8).abs() < 1e5);
+/// ```
+#[doc(alias = "vg")]
+#[must_use]
+pub fn group_velocity(frequency: f64, cutoff_frequency: f64) -> f64 {
+    crate::constants::SPEED_OF_LIGHT * dispersion_factor(frequency, cutoff_frequency)
+}
+
+/// Wave impedance (ohms) of a TE or TM mode above cutoff.
+///
+/// Z_TE = η₀ / √(1 − (f_c/f)²), Z_TM = η₀ · √(1 − (f_c/f)²)
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::waveguide::{wave_impedance, ModeType};
+/// let z_te = wave_impedance(ModeType::Te, 10.0e9, 6.557e9);
+/// assert!((z_te - 498.9).abs() < 0.5);
+/// ```
+#[doc(alias = "ZTE")]
+#[doc(alias = "ZTM")]
+#[must_use]
+pub fn wave_impedance(mode: ModeType, frequency: f64, cutoff_frequency: f64) -> f64 {
+    let factor = dispersion_factor(frequency, cutoff_frequency);
+    match mode {
+        ModeType::Te => crate::constants::FREE_SPACE_IMPEDANCE / factor,
+        ModeType::Tm => crate::constants::FREE_SPACE_IMPEDANCE * factor,
+    }
+}
+
+/// Conductor surface resistance Rs = √(π·f·μ₀ / σ) in ohms per square.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::waveguide::surface_resistance;
+/// // Copper (σ = 5.8e7 S/m) at 10 GHz: ~26 mΩ/□
+/// let rs = surface_resistance(10.0e9, 5.8e7);
+/// assert!((rs - 0.0261).abs() < 0.0001);
+/// ```
+#[doc(alias = "Rs")]
+#[must_use]
+pub fn surface_resistance(frequency: f64, conductivity: f64) -> f64 {
+    (PI * frequency * crate::constants::VACUUM_PERMEABILITY / conductivity).sqrt()
+}
+
+/// Conductor attenuation (dB/m) of the TE10 mode in a rectangular waveguide.
+///
+/// α_c = Rs / (a³·b·β·k·η₀) · (2bπ² + a³k²) Np/m, converted to dB/m.
+///
+/// # Arguments
+///
+/// * `frequency` - Operating frequency in Hz (above TE10 cutoff)
+/// * `width` - Broad wall dimension `a` in meters
+/// * `height` - Narrow wall dimension `b` in meters
+/// * `conductivity` - Wall conductivity in S/m
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::waveguide::rectangular_te10_attenuation_db_per_meter;
+/// // Copper WR-90 at 10 GHz: ~0.11 dB/m
+/// let alpha = rectangular_te10_attenuation_db_per_meter(10.0e9, 0.02286, 0.01016, 5.8e7);
+/// assert!((alpha - 0.110).abs() < 0.005);
+/// ```
+#[doc(alias = "alpha")]
+#[doc(alias = "insertion loss")]
+#[must_use]
+pub fn rectangular_te10_attenuation_db_per_meter(
+    frequency: f64,
+    width: f64,
+    height: f64,
+    conductivity: f64,
+) -> f64 {
+    let rs = surface_resistance(frequency, conductivity);
+    let k = 2.0 * PI / crate::frequency::frequency_to_wavelength(frequency);
+    let kc = PI / width;
+    let beta = (k * k - kc * kc).sqrt();
+    let a3 = width.powi(3);
+
+    let alpha_np = rs / (a3 * height * beta * k * crate::constants::FREE_SPACE_IMPEDANCE)
+        * (2.0 * height * PI * PI + a3 * k * k);
+    alpha_np * NEPER_TO_DB
+}
+
+/// Conductor attenuation (dB/m) of the TE11 mode in a circular waveguide.
+///
+/// α_c = Rs / (a·k·η₀·β) · (k_c² + k²/(p′₁₁² − 1)) Np/m, converted to dB/m.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::waveguide::circular_te11_attenuation_db_per_meter;
+/// // Copper, 10 mm radius at 12 GHz
+/// let alpha = circular_te11_attenuation_db_per_meter(12.0e9, 0.01, 5.8e7);
+/// assert!(alpha > 0.0 && alpha < 0.2);
+/// ```
+#[doc(alias = "alpha")]
+#[must_use]
+pub fn circular_te11_attenuation_db_per_meter(
+    frequency: f64,
+    radius: f64,
+    conductivity: f64,
+) -> f64 {
+    let p11 = BESSEL_DERIVATIVE_ZEROS[1][0];
+    let rs = surface_resistance(frequency, conductivity);
+    let k = 2.0 * PI / crate::frequency::frequency_to_wavelength(frequency);
+    let kc = p11 / radius;
+    let beta = (k * k - kc * kc).sqrt();
+
+    let alpha_np = rs / (radius * k * crate::constants::FREE_SPACE_IMPEDANCE * beta)
+        * (kc * kc + k * k / (p11 * p11 - 1.0));
+    alpha_np * NEPER_TO_DB
+}
+
+/// A standard EIA WR-xx rectangular waveguide size.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WaveguideBand {
+    /// EIA designation, e.g. `"WR-90"`.
+    pub name: &'static str,
+    /// Inner broad wall dimension in meters.
+    pub width: f64,
+    /// Inner narrow wall dimension in meters.
+    pub height: f64,
+    /// Lower edge of the recommended TE10 operating range in Hz.
+    pub min_frequency: f64,
+    /// Upper edge of the recommended TE10 operating range in Hz.
+    pub max_frequency: f64,
+}
+
+impl WaveguideBand {
+    /// TE10 cutoff frequency (Hz) of this waveguide size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfconversions::waveguide::waveguide_by_name;
+    /// let wr90 = waveguide_by_name("WR-90").unwrap();
+    /// assert!((wr90.cutoff_frequency() - 6.557e9).abs() < 1e6);
+    /// ```
+    #[must_use]
+    pub fn cutoff_frequency(&self) -> f64 {
+        rectangular_cutoff_frequency(self.width, self.height, ModeType::Te, 1, 0)
+    }
+}
+
+const fn wr(
+    name: &'static str,
+    a_in: f64,
+    b_in: f64,
+    f_min_ghz: f64,
+    f_max_ghz: f64,
+) -> WaveguideBand {
+    WaveguideBand {
+        name,
+        width: a_in * INCH,
+        height: b_in * INCH,
+        min_frequency: f_min_ghz * 1e9,
+        max_frequency: f_max_ghz * 1e9,
+    }
+}
+
+/// Standard WR-xx waveguide sizes with their recommended operating ranges,
+/// ordered from largest to smallest.
+pub const STANDARD_WAVEGUIDES: [WaveguideBand; 33] = [
+    wr("WR-2300", 23.0, 11.5, 0.32, 0.49),
+    wr("WR-2100", 21.0, 10.5, 0.35, 0.53),
+    wr("WR-1800", 18.0, 9.0, 0.43, 0.62),
+    wr("WR-1500", 15.0, 7.5, 0.49, 0.75),
+    wr("WR-1150", 11.5, 5.75, 0.64, 0.96),
+    wr("WR-975", 9.75, 4.875, 0.75, 1.12),
+    wr("WR-770", 7.7, 3.85, 0.96, 1.45),
+    wr("WR-650", 6.5, 3.25, 1.12, 1.70),
+    wr("WR-510", 5.1, 2.55, 1.45, 2.20),
+    wr("WR-430", 4.3, 2.15, 1.70, 2.60),
+    wr("WR-340", 3.4, 1.7, 2.20, 3.30),
+    wr("WR-284", 2.84, 1.34, 2.60, 3.95),
+    wr("WR-229", 2.29, 1.145, 3.30, 4.90),
+    wr("WR-187", 1.872, 0.872, 3.95, 5.85),
+    wr("WR-159", 1.59, 0.795, 4.90, 7.05),
+    wr("WR-137", 1.372, 0.622, 5.85, 8.20),
+    wr("WR-112", 1.122, 0.497, 7.05, 10.0),
+    wr("WR-90", 0.9, 0.4, 8.20, 12.4),
+    wr("WR-75", 0.75, 0.375, 10.0, 15.0),
+    wr("WR-62", 0.622, 0.311, 12.4, 18.0),
+    wr("WR-51", 0.51, 0.255, 15.0, 22.0),
+    wr("WR-42", 0.42, 0.17, 18.0, 26.5),
+    wr("WR-34", 0.34, 0.17, 22.0, 33.0),
+    wr("WR-28", 0.28, 0.14, 26.5, 40.0),
+    wr("WR-22", 0.224, 0.112, 33.0, 50.0),
+    wr("WR-19", 0.188, 0.094, 40.0, 60.0),
+    wr("WR-15", 0.148, 0.074, 50.0, 75.0),
+    wr("WR-12", 0.122, 0.061, 60.0, 90.0),
+    wr("WR-10", 0.1, 0.05, 75.0, 110.0),
+    wr("WR-8", 0.08, 0.04, 90.0, 140.0),
+    wr("WR-6", 0.065, 0.0325, 110.0, 170.0),
+    wr("WR-5", 0.051, 0.0255, 140.0, 220.0),
+    wr("WR-3", 0.034, 0.017, 220.0, 325.0),
+];
+
+/// Look up a standard waveguide by its designation.
+///
+/// Matching ignores case and an optional hyphen, so `"WR-90"`, `"wr90"`
+/// and `"WR90"` are equivalent.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::waveguide::waveguide_by_name;
+/// let wr28 = waveguide_by_name("wr28").unwrap();
+/// assert_eq!(wr28.name, "WR-28");
+/// assert!(waveguide_by_name("WR-1").is_none());
+/// ```
+#[doc(alias = "WR")]
+#[must_use]
+pub fn waveguide_by_name(name: &str) -> Option<&'static WaveguideBand> {
+    let normalize = |s: &str| s.replace('-', "").to_ascii_uppercase();
+    let wanted = normalize(name);
+    STANDARD_WAVEGUIDES
+        .iter()
+        .find(|band| normalize(band.name) == wanted)
+}
+
+/// All standard waveguides whose recommended range contains `frequency` (Hz).
+///
+/// Adjacent sizes overlap at their band edges, so more than one may be returned.
+///
+/// # Examples
+///
+/// ```
+/// use rfconversions::waveguide::waveguides_for_frequency;
+/// let names: Vec<_> = waveguides_for_frequency(10.0e9).iter().map(|b| b.name).collect();
+/// assert_eq!(names, vec!["WR-112", "WR-90", "WR-75"]);
+/// ```
+#[doc(alias = "WR")]
+#[must_use]
+pub fn waveguides_for_frequency(frequency: f64) -> Vec<&'static WaveguideBand> {
+    STANDARD_WAVEGUIDES
+        .iter()
+        .filter(|band| frequency >= band.min_frequency && frequency <= band.max_frequency)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ModeType;
+
+    const WR90_A: f64 = 0.02286;
+    const WR90_B: f64 = 0.01016;
+
+    #[test]
+    fn rectangular_te10_cutoff_wr90() {
+        let fc = super::rectangular_cutoff_frequency(WR90_A, WR90_B, ModeType::Te, 1, 0);
+        // c / (2a) = 299792458 / 0.04572
+        assert!((fc - 6_557_140_376.2).abs() < 1.0);
+    }
+
+    #[test]
+    fn rectangular_te20_is_twice_te10() {
+        let te10 = super::rectangular_cutoff_frequency(WR90_A, WR90_B, ModeType::Te, 1, 0);
+        let te20 = super::rectangular_cutoff_frequency(WR90_A, WR90_B, ModeType::Te, 2, 0);
+        assert!((te20 - 2.0 * te10).abs() < 1e-3);
+    }
+
+    #[test]
+    fn rectangular_te11_and_tm11_share_cutoff() {
+        let te = super::rectangular_cutoff_frequency(WR90_A, WR90_B, ModeType::Te, 1, 1);
+        let tm = super::rectangular_cutoff_frequency(WR90_A, WR90_B, ModeType::Tm, 1, 1);
+        assert_eq!(te, tm);
+        // sqrt(6.557² + 14.753²) ≈ 16.15 GHz
+        assert!((te - 16.15e9).abs() < 0.01e9);
+    }
+
+    #[test]
+    #[should_panic(expected = "TM modes require m >= 1 and n >= 1")]
+    fn rectangular_tm10_panics() {
+        let _ = super::rectangular_cutoff_frequency(WR90_A, WR90_B, ModeType::Tm, 1, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "TE00 does not exist")]
+    fn rectangular_te00_panics() {
+        let _ = super::rectangular_cutoff_frequency(WR90_A, WR90_B, ModeType::Te, 0, 0);
+    }
+
+    #[test]
+    fn circular_mode_ordering() {
+        // Dominant TE11, then TM01, then TE21, then TE01/TM11 (degenerate)
+        let r = 0.01;
+        let te11 = super::circular_cutoff_frequency(r, ModeType::Te, 1, 1);
+        let tm01 = super::circular_cutoff_frequency(r, ModeType::Tm, 0, 1);
+        let te21 = super::circular_cutoff_frequency(r, ModeType::Te, 2, 1);
+        let te01 = super::circular_cutoff_frequency(r, ModeType::Te, 0, 1);
+        let tm11 = super::circular_cutoff_frequency(r, ModeType::Tm, 1, 1);
+        assert!(te11 < tm01 && tm01 < te21 && te21 < te01);
+        assert_eq!(te01, tm11);
+        // TM01 / TE11 = 2.405 / 1.841
+        assert!((tm01 / te11 - 1.3061).abs() < 1e-4);
+    }
+
+    #[test]
+    #[should_panic(expected = "radial index m must be 1..=3")]
+    fn circular_zero_radial_index_panics() {
+        let _ = super::circular_cutoff_frequency(0.01, ModeType::Te, 1, 0);
+    }
+
+    #[test]
+    fn guide_wavelength_longer_than_free_space() {
+        let lg = super::guide_wavelength(10.0e9, 6.557e9);
+        let l0 = crate::frequency::frequency_to_wavelength(10.0e9);
+        assert!(lg > l0);
+        assert!((lg - 0.039_70).abs() < 1e-4);
+    }
+
+    #[test]
+    fn guide_wavelength_below_cutoff_is_nan() {
+        assert!(super::guide_wavelength(5.0e9, 6.557e9).is_nan());
+    }
+
+    #[test]
+    fn phase_times_group_velocity_is_c_squared() {
+        let vp = super::phase_velocity(12.0e9, 6.557e9);
+        let vg = super::group_velocity(12.0e9, 6.557e9);
+        let c = crate::constants::SPEED_OF_LIGHT;
+        assert!((vp * vg / (c * c) - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn wave_impedance_te_times_tm_is_eta_squared() {
+        let te = super::wave_impedance(ModeType::Te, 12.0e9, 6.557e9);
+        let tm = super::wave_impedance(ModeType::Tm, 12.0e9, 6.557e9);
+        let eta = crate::constants::FREE_SPACE_IMPEDANCE;
+        assert!(te > eta && tm < eta);
+        assert!((te * tm / (eta * eta) - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn wave_impedance_approaches_eta_far_above_cutoff() {
+        let te = super::wave_impedance(ModeType::Te, 1.0e15, 6.557e9);
+        assert!((te - crate::constants::FREE_SPACE_IMPEDANCE).abs() < 1e-6);
+    }
+
+    #[test]
+    fn surface_resistance_copper_10ghz() {
+        let rs = super::surface_resistance(10.0e9, 5.8e7);
+        assert!((rs - 0.02609).abs() < 1e-4);
+    }
+
+    #[test]
+    fn wr90_attenuation_matches_handbook() {
+        // Pozar example 3.1 style: copper WR-90 at 10 GHz ≈ 0.11 dB/m
+        let alpha = super::rectangular_te10_attenuation_db_per_meter(10.0e9, WR90_A, WR90_B, 5.8e7);
+        assert!((alpha - 0.110).abs() < 0.005, "got {alpha}");
+    }
+
+    #[test]
+    fn attenuation_rises_near_cutoff() {
+        let near = super::rectangular_te10_attenuation_db_per_meter(7.0e9, WR90_A, WR90_B, 5.8e7);
+        let mid = super::rectangular_te10_attenuation_db_per_meter(10.0e9, WR90_A, WR90_B, 5.8e7);
+        assert!(near > mid);
+    }
+
+    #[test]
+    fn circular_te11_attenuation_positive() {
+        let alpha = super::circular_te11_attenuation_db_per_meter(12.0e9, 0.01, 5.8e7);
+        assert!(alpha > 0.0 && alpha < 0.2, "got {alpha}");
+    }
+
+    #[test]
+    fn standard_table_cutoffs_are_below_band() {
+        for band in &super::STANDARD_WAVEGUIDES {
+            let fc = band.cutoff_frequency();
+            assert!(fc < band.min_frequency, "{} cutoff above band", band.name);
+            assert!(band.min_frequency < band.max_frequency);
+        }
+    }
+
+    #[test]
+    fn waveguide_by_name_normalizes() {
+        let a = super::waveguide_by_name("WR-62").unwrap();
+        let b = super::waveguide_by_name("wr62").unwrap();
+        assert_eq!(a, b);
+        assert!((a.width - 0.0157988).abs() < 1e-6);
+    }
+
+    #[test]
+    fn waveguides_for_frequency_ka_band() {
+        let names: Vec<_> = super::waveguides_for_frequency(30.0e9)
+            .iter()
+            .map(|b| b.name)
+            .collect();
+        assert_eq!(names, vec!["WR-34", "WR-28"]);
+    }
+
+    #[test]
+    fn waveguides_for_frequency_out_of_table() {
+        assert!(super::waveguides_for_frequency(1.0e6).is_empty());
+    }
+}
@@ -125,3 +125,611 @@ fn speed_of_light() {
     let c = rfconversions::constants::SPEED_OF_LIGHT;
     assert_eq!(c, 299_792_458.0);
 }
+
+// === Section 8: Waveguide ===
+
+#[test]
+fn waveguide_wr90_propagation() {
+    use rfconversions::waveguide::{self, ModeType};
+
+    let wr90 = waveguide::waveguide_by_name("WR-90").unwrap();
+    let fc = wr90.cutoff_frequency();
+    assert!((fc - 6.557e9).abs() < 1e6);
+
+    let lg = waveguide::guide_wavelength(10.0e9, fc);
+    assert!((lg - 0.0397).abs() < 1e-4);
+
+    let z_te = waveguide::wave_impedance(ModeType::Te, 10.0e9, fc);
+    assert!((z_te - 499.0).abs() < 1.0);
+
+    let alpha = waveguide::rectangular_te10_attenuation_db_per_meter(
+        10.0e9,
+        wr90.width,
+        wr90.height,
+        5.8e7,
+    );
+    assert!((alpha - 0.11).abs() < 0.005);
+}
+
+#[test]
+fn waveguide_sizes_for_30ghz() {
+    let names: Vec<_> = rfconversions::waveguide::waveguides_for_frequency(30.0e9)
+        .iter()
+        .map(|b| b.name)
+        .collect();
+    assert_eq!(names, vec!["WR-34", "WR-28"]);
+}
+
+// === Section 9: Impedance Matching ===
+
+#[test]
+fn matching_l_section_complex_load() {
+    use rfconversions::complex::Complex;
+    let solutions = rfconversions::matching::l_section(
+        Complex::new(50.0, 0.0),
+        Complex::new(40.0, 30.0),
+        2.4e9,
+    );
+    assert_eq!(solutions.len(), 4);
+}
+
+#[test]
+fn matching_pi_network_bandwidth() {
+    use rfconversions::complex::Complex;
+    use rfconversions::matching::{pi_network, MatchResponse};
+    let pi = pi_network(
+        Complex::new(50.0, 0.0),
+        Complex::new(200.0, 0.0),
+        100.0e6,
+        5.0,
+        MatchResponse::Lowpass,
+    );
+    assert_eq!(pi.bandwidth, 20.0e6);
+}
+
+#[test]
+fn matching_quarter_wave() {
+    assert_eq!(
+        rfconversions::matching::quarter_wave_impedance(50.0, 200.0),
+        100.0
+    );
+    let bw = rfconversions::matching::quarter_wave_bandwidth(1.0e9, 50.0, 200.0, 1.5);
+    assert!((bw - 351.0e6).abs() < 1.0e6);
+}
+
+// === Section 10: Attenuator Pads ===
+
+#[test]
+fn attenuator_snapped_pi_pad() {
+    use rfconversions::attenuator::{self, ResistorSeries};
+
+    let ideal = attenuator::pi_pad(10.0, 50.0);
+    let real = attenuator::snap_pad(&ideal, ResistorSeries::E96);
+    assert!((attenuator::pad_attenuation_db(&real, 50.0) - 10.07).abs() < 0.01);
+    assert!(attenuator::pad_return_loss_db(&real, 50.0) > 40.0);
+
+    let watts = attenuator::pad_dissipation(&ideal, 50.0, 30.0);
+    assert!((watts.iter().sum::<f64>() - 0.9).abs() < 1e-9);
+}
+
+#[test]
+fn attenuator_minimum_loss_pad() {
+    let mlp = rfconversions::attenuator::minimum_loss_pad(75.0, 50.0);
+    assert!((mlp.loss_db - 5.72).abs() < 0.01);
+}
+
+// === Section 11: Smith Chart ===
+
+#[test]
+fn smith_vswr_from_impedance() {
+    use rfconversions::complex::Complex;
+    let gamma = rfconversions::smith::impedance_to_reflection_coefficient(Complex::new(2.0, 0.0));
+    let vswr = rfconversions::smith::vswr_from_reflection_magnitude(gamma.norm());
+    assert!((vswr - 2.0).abs() < 1e-12);
+}
+
+#[test]
+fn smith_chart_svg() {
+    use rfconversions::complex::Complex;
+    use rfconversions::smith::{self, SmithChart};
+
+    let gamma_opt = Complex::from_polar(0.62, 100.0_f64.to_radians());
+    let nf_circle = smith::noise_circle(1.6, gamma_opt, 0.4, 2.0);
+
+    let sweep = [(0.4, -0.8), (0.7, -0.2), (1.0, 0.3), (1.6, 0.9)].map(Complex::from);
+    let mut chart = SmithChart::new(400.0);
+    chart.add_impedance_sweep(&sweep, "blue");
+    chart.add_circle(smith::vswr_circle(2.0), "red");
+    chart.add_circle(nf_circle, "green");
+    let svg = chart.to_svg();
+    assert!(svg.contains("stroke=\"blue\"") && svg.contains("stroke=\"green\""));
+}
+
+// === Section 12: Filters ===
+
+#[test]
+fn filter_chebyshev_bandpass() {
+    use rfconversions::filter::{self, Approximation, FilterResponse};
+
+    let proto = filter::prototype(Approximation::Chebyshev { ripple_db: 0.1 }, 5).unwrap();
+    let bp = FilterResponse::Bandpass {
+        center: 1e9,
+        bandwidth: 100e6,
+    };
+    let elements = filter::transform(&proto, bp, 50.0);
+    assert_eq!(elements.len(), 5);
+
+    let il = filter::insertion_loss_db(&proto, bp, 1.15e9);
+    assert!(il > 20.0);
+    let enbw = filter::noise_bandwidth(&proto, bp);
+    assert!(enbw > 100e6 && enbw < 120e6);
+}
+
+#[test]
+fn filter_elliptic_lowpass() {
+    use rfconversions::filter::{self, Approximation, Element, FilterResponse};
+
+    let cauer = filter::prototype(
+        Approximation::Elliptic {
+            ripple_db: 0.1,
+            stopband_db: 50.0,
+        },
+        5,
+    )
+    .unwrap();
+    let lp = filter::transform(&cauer, FilterResponse::Lowpass { cutoff: 500e6 }, 50.0);
+    assert!(matches!(lp[1], Element::SeriesTank { .. }));
+
+    let bp = FilterResponse::Bandpass {
+        center: 1e9,
+        bandwidth: 100e6,
+    };
+    let bp_cauer = filter::transform(&cauer, bp, 50.0);
+    assert!(matches!(bp_cauer[1], Element::SeriesResonatorPair { .. }));
+}
+
+// === Section 13: Noise Bandwidth ===
+
+#[test]
+fn bandwidth_rbw_thermal_noise() {
+    let enbw = rfconversions::bandwidth::synchronously_tuned_noise_bandwidth(10e3, 4);
+    assert!((enbw - 11.28e3).abs() < 10.0);
+    let ktb = rfconversions::noise::noise_power_from_bandwidth(290.0, enbw);
+    assert!(ktb > 4.5e-17 && ktb < 4.6e-17);
+}
+
+#[test]
+fn bandwidth_pulse_shaping_and_windows() {
+    use rfconversions::bandwidth::{self, Window};
+
+    assert!((bandwidth::raised_cosine_noise_bandwidth(1e6, 0.35) - 912.5e3).abs() < 1e-6);
+    assert_eq!(
+        bandwidth::root_raised_cosine_noise_bandwidth(1e6, 0.35),
+        1e6
+    );
+    let bins = bandwidth::window_noise_bandwidth_bins(Window::BlackmanHarris);
+    assert!((bins - 2.0044).abs() < 1e-3);
+    let bin_enbw = bandwidth::window_noise_bandwidth(Window::BlackmanHarris, 1e6, 4096);
+    assert!((bin_enbw - 489.3).abs() < 0.1);
+}
+
+// === Section 14: Link Quality ===
+
+#[test]
+fn snr_link_chain() {
+    use rfconversions::snr;
+
+    let cn0 = snr::cn0_from_received_power(-100.0, 500.0);
+    assert!((cn0 - 71.6).abs() < 0.05);
+    let rs = snr::symbol_rate(2e6, 2, 0.5);
+    assert_eq!(rs, 2e6);
+    let ebn0 = snr::ebn0_from_cn0(cn0, 2e6);
+    assert!((ebn0 - 8.6).abs() < 0.05);
+    let esn0 = snr::esn0_from_ebn0(ebn0, 2, 0.5);
+    assert!((esn0 - ebn0).abs() < 1e-12);
+    let snr_db = snr::snr_from_esn0(esn0, 0.35);
+    assert!((esn0 - snr_db - 1.303).abs() < 1e-3);
+    assert!((snr::occupied_bandwidth(rs, 0.35) - 2.7e6).abs() < 1e-6);
+}
+
+// === Section 15: Bit Error Rate ===
+
+#[test]
+fn ber_required_ebn0_and_cn0() {
+    use rfconversions::ber::{self, Modulation};
+
+    let ber16 = ber::bit_error_rate(Modulation::Qam(16), 12.0);
+    assert!(ber16 > 1e-4 && ber16 < 2e-4);
+    let ebn0 = ber::required_ebn0_db(Modulation::Psk(8), 1e-6);
+    assert!((ebn0 - 14.0).abs() < 0.1);
+    let cn0 = rfconversions::snr::cn0_from_ebn0(ebn0, 10e6);
+    assert!((cn0 - ebn0 - 70.0).abs() < 1e-9);
+}
+
+#[test]
+fn ber_q_function() {
+    let q = rfconversions::ber::q_function(4.75);
+    assert!((q - 1.02e-6).abs() < 0.01e-6);
+    assert!((rfconversions::ber::erfc(2.0) - 0.004_677_734_981_047_266).abs() < 1e-15);
+}
+
+// === Section 16: Shannon Capacity ===
+
+#[test]
+fn capacity_bounds_and_gap() {
+    use rfconversions::ber::Modulation;
+    use rfconversions::capacity;
+
+    let c = capacity::shannon_capacity(20e6, 15.0);
+    assert!((c - 100.6e6).abs() < 0.1e6);
+    let c_rx = capacity::shannon_capacity_from_power(20e6, -85.0, 500.0);
+    assert!(c_rx > 0.0 && c_rx < c);
+
+    assert!((capacity::minimum_ebn0_db(4.0) - 5.74).abs() < 0.01);
+    assert!((capacity::minimum_ebn0_db(0.0) + 1.59).abs() < 0.01);
+
+    let eta = capacity::operating_spectral_efficiency(3, 0.75, 0.2);
+    let gap = capacity::shannon_gap_db(7.0, eta);
+    assert!(gap > 5.0 && gap < 6.0);
+
+    let uncoded_gap = capacity::uncoded_shannon_gap_db(Modulation::Qam(16), 1e-6);
+    assert!((uncoded_gap - 8.65).abs() < 0.1);
+}
+
+// === Section 17: EVM, MER and Impairment Budgets ===
+
+#[test]
+fn evm_conversions_and_budget() {
+    use rfconversions::evm::{self, EvmBudget};
+
+    assert!((evm::evm_percent_to_db(3.0) + 30.46).abs() < 0.01);
+    assert!((evm::mer_from_evm_percent(3.0) - 30.46).abs() < 0.01);
+    assert!((evm::evm_percent_from_snr(25.0) - 5.62).abs() < 0.01);
+
+    let budget = EvmBudget {
+        rms_phase_error_deg: 0.5,
+        snr_db: 35.0,
+        iq_gain_imbalance_db: 0.05,
+        iq_phase_imbalance_deg: 0.5,
+        output_power_dbm: 20.0,
+        output_p1db_dbm: 30.0,
+    };
+    let [phase, thermal, iq, compression] = budget.contributions();
+    assert!(thermal > phase && phase > compression && compression > iq);
+    assert!((budget.total_percent() - 2.22).abs() < 0.01);
+}
+
+// === Section 18: Phase Noise Integration ===
+
+#[test]
+fn phase_noise_integration() {
+    use rfconversions::phase_noise;
+
+    let profile = [
+        (1e3, -85.0),
+        (1e4, -95.0),
+        (1e5, -100.0),
+        (1e6, -125.0),
+        (1e7, -150.0),
+    ];
+    let pn = phase_noise::phase_noise_at(&profile, 3e5);
+    assert!(pn < -100.0 && pn > -125.0);
+
+    let a = phase_noise::integrated_phase_noise_dbc(&profile, 1e3, 1e7);
+    let phi = phase_noise::rms_phase_error_deg(&profile, 1e3, 1e7);
+    let expected_phi = (2.0 * 10.0_f64.powf(a / 10.0)).sqrt().to_degrees();
+    assert!((phi - expected_phi).abs() < 1e-12);
+
+    let tj = phase_noise::rms_jitter(&profile, 1e3, 1e7, 2.4e9);
+    assert!((tj - phi.to_radians() / (2.0 * std::f64::consts::PI * 2.4e9)).abs() < 1e-24);
+    assert!(phase_noise::residual_fm(&profile, 1e3, 1e7) > 0.0);
+}
+
+// === Section 19: Frequency Multiplication and PLL Phase Noise ===
+
+#[test]
+fn pll_phase_noise() {
+    use rfconversions::phase_noise::{self, Pll};
+
+    assert!((phase_noise::multiply_phase_noise(-150.0, 10.0) - (-130.0)).abs() < 1e-12);
+    let tcxo = [(1e2, -130.0), (1e3, -150.0), (1e4, -160.0), (1e7, -165.0)];
+    let at_5ghz = phase_noise::scale_profile(&tcxo, 100e6, 5e9);
+    assert!((at_5ghz[1].1 - (-116.0206)).abs() < 1e-4);
+
+    let pll = Pll {
+        reference_frequency: 100e6,
+        pfd_frequency: 100e6,
+        output_frequency: 5e9,
+        loop_bandwidth: 200e3,
+        damping: 0.707,
+        reference: tcxo.to_vec(),
+        vco: vec![(1e3, -50.0), (1e5, -100.0), (1e6, -125.0), (1e7, -145.0)],
+        pfd_figure_of_merit: -226.0,
+    };
+    let offsets: Vec<f64> = (0..=40)
+        .map(|i| 10.0_f64.powf(3.0 + i as f64 / 10.0))
+        .collect();
+    let output = pll.output_profile(&offsets);
+    assert_eq!(output.len(), 41);
+    // Locked output is far quieter than the free-running VCO close in
+    assert!(output[0].1 < -100.0);
+    let tj = phase_noise::rms_jitter(&output, 1e3, 1e7, 5e9);
+    assert!(tj > 10e-15 && tj < 1e-12);
+}
+
+// === Section 20: ADC/DAC Conversions ===
+
+#[test]
+fn adc_conversions() {
+    use rfconversions::adc;
+
+    assert!((adc::ideal_snr_db(14) - 86.04).abs() < 0.01);
+    assert!((adc::enob(70.0) - 11.33).abs() < 0.01);
+    let fs = adc::full_scale_dbm(1.7, 100.0);
+    assert!((fs - 5.58).abs() < 0.01);
+    assert!((adc::dbm_to_dbfs(-30.0, fs) - (-35.58)).abs() < 0.01);
+    assert!((adc::noise_spectral_density_dbfs_per_hz(70.0, 1e9) - (-156.99)).abs() < 0.01);
+    assert!((adc::processing_gain_db(1e9, 10e6) - 16.99).abs() < 0.01);
+    let nf = adc::equivalent_noise_figure(fs, 70.0, 1e9);
+    assert!((nf - 22.57).abs() < 0.01);
+}
+
+// === Section 21: Receiver Gain Line-up ===
+
+#[test]
+fn receiver_gain_lineup() {
+    use rfconversions::lineup::{self, Converter, Stage};
+
+    let adc = Converter::from_snr(4.0, 70.0, 1e9);
+    let g = lineup::required_gain_db(150.0, 1.5, adc.noise_spectral_density_dbm_per_hz, 10.0);
+    assert!(g > 30.0 && g < 40.0);
+
+    let candidates = [
+        Stage {
+            noise_figure_db: 1.0,
+            gain_db: 20.0,
+            output_p1db_dbm: 15.0,
+        },
+        Stage {
+            noise_figure_db: 3.0,
+            gain_db: 15.0,
+            output_p1db_dbm: 20.0,
+        },
+        Stage {
+            noise_figure_db: 4.0,
+            gain_db: 15.0,
+            output_p1db_dbm: 20.0,
+        },
+    ];
+    let rows = lineup::trade(150.0, &candidates, adc, -50.0);
+    assert_eq!(rows.len(), 3);
+    for pair in rows.windows(2) {
+        assert!(pair[1].noise_margin_db > pair[0].noise_margin_db);
+        assert!(pair[1].sensitivity_degradation_db < pair[0].sensitivity_degradation_db);
+        assert!(pair[1].headroom_db() < pair[0].headroom_db());
+    }
+    let best = lineup::optimize(150.0, &candidates, adc, -50.0, 10.0).unwrap();
+    assert_eq!(best.stages, 2);
+    assert!(best.noise_margin_db >= 10.0 && best.headroom_db() >= 0.0);
+}
+
+// === Section 22: Power Summation ===
+
+#[test]
+fn power_summation() {
+    use rfconversions::power;
+
+    assert!((power::sum_dbm(&[-100.0, -103.0, -106.0]) - (-97.56)).abs() < 0.01);
+    assert!((power::subtract_dbm(-96.99, -100.0) - (-100.0)).abs() < 0.01);
+    assert!((power::coherent_sum_dbm(&[(0.0, 0.0), (0.0, 0.0)]) - 6.02).abs() < 0.01);
+    assert!((power::average_dbm(&[10.0, 13.0, 7.0]) - 10.66).abs() < 0.01);
+    assert_eq!(power::dbm_to_dbc(-60.0, 5.0), -65.0);
+    assert_eq!(power::dbc_to_dbm(-65.0, 5.0), -60.0);
+}
+
+// === Section 23: PAPR and Crest Factor ===
+
+#[test]
+fn papr_and_crest_factor() {
+    use rfconversions::ber::Modulation;
+    use rfconversions::papr;
+
+    assert!((papr::crest_factor(3.01) - 2.0_f64.sqrt()).abs() < 1e-3);
+    assert!((papr::multitone_papr_db(2) - 3.01).abs() < 0.01);
+    assert!((papr::constellation_papr_db(Modulation::Qam(64)) - 3.68).abs() < 0.01);
+    let ofdm = papr::ofdm_papr_db(1024, 1e-4);
+    assert!((ofdm - 12.08).abs() < 0.01);
+    assert!((papr::max_average_output_dbm(40.0, ofdm) - 27.92).abs() < 0.01);
+    assert!((papr::max_average_input_dbm(40.0, 25.0, ofdm) - 3.92).abs() < 0.01);
+}
+
+// === Section 24: Power Amplifier Models ===
+
+#[test]
+fn power_amplifier_models() {
+    use rfconversions::amplifier::{self, Model};
+
+    let sspa = Model::Rapp {
+        gain_db: 30.0,
+        saturation_dbm: 40.0,
+        smoothness: 2.0,
+    };
+    assert!(sspa.output_dbm(5.0) < 35.0);
+    let (ip1db, op1db) = sspa.p1db().unwrap();
+    assert!((op1db - ip1db - 29.0).abs() < 1e-9);
+    let (ip3db, op3db) = sspa.p3db().unwrap();
+    assert!(ip3db > ip1db && op3db > op1db);
+    assert!((sspa.saturated_power_dbm() - 40.0).abs() < 1e-3);
+
+    let twt = Model::saleh(25.0, 45.0);
+    assert_eq!(twt.phase_deg(10.0), 0.0);
+
+    let sweep: Vec<(f64, f64)> = (-20..=15)
+        .map(|p| (p as f64, sspa.output_dbm(p as f64)))
+        .collect();
+    let fitted = amplifier::fit_rapp(&sweep);
+    assert!((fitted.small_signal_gain_db() - 30.0).abs() < 1e-3);
+}
+
+// === Section 25: Compression Curve Analysis ===
+
+#[test]
+fn compression_curve_analysis() {
+    use rfconversions::{compression, p1db};
+
+    let sweep = [
+        (-20.0, 0.0),
+        (-15.0, 5.0),
+        (-10.0, 10.0),
+        (-5.0, 14.5),
+        (0.0, 17.0),
+        (5.0, 18.5),
+        (10.0, 19.2),
+        (15.0, 19.0),
+    ];
+    let gain = compression::small_signal_gain_db(&sweep);
+    assert!((gain - 20.0).abs() < 1e-12);
+    let (ip1db, op1db) = compression::p1db(&sweep).unwrap();
+    assert!((ip1db - (-4.0)).abs() < 1e-12 && (op1db - 15.0).abs() < 1e-12);
+    let (ip3db, op3db) = compression::p3db(&sweep).unwrap();
+    assert!(ip3db.abs() < 1e-12 && (op3db - 17.0).abs() < 1e-12);
+    let (pin_sat, psat) = compression::saturated_power(&sweep);
+    assert!(pin_sat > 5.0 && pin_sat < 15.0 && psat >= 19.2);
+    assert!(compression::gain_expansion(&sweep, 0.1).is_none());
+    assert!((p1db::output_to_input_db(op1db, gain) - ip1db).abs() < 1e-12);
+}
+
+// === Section 26: Amplifier Efficiency and Thermal ===
+
+#[test]
+fn amplifier_efficiency_and_thermal() {
+    use rfconversions::efficiency::{self, Class};
+
+    let pdc = efficiency::dc_power(28.0, 2.0);
+    assert_eq!(pdc, 56.0);
+    assert!((efficiency::drain_efficiency_percent(43.0, pdc) - 35.6).abs() < 0.1);
+    assert!((efficiency::power_added_efficiency_percent(43.0, 30.0, pdc) - 33.8).abs() < 0.1);
+    let diss = efficiency::dissipated_power(43.0, 30.0, pdc);
+    assert!((diss - 37.1).abs() < 0.1);
+    let tj = efficiency::junction_temperature(diss, 1.8, 85.0);
+    assert!((tj - 151.7).abs() < 0.1);
+    assert!((efficiency::efficiency_at_backoff_percent(Class::B, 8.0) - 31.3).abs() < 0.1);
+}
+
+// === Section 27: IP2 and Harmonic Distortion ===
+
+#[test]
+fn ip2_and_harmonic_distortion() {
+    use rfconversions::intercept;
+
+    assert_eq!(intercept::iip2_to_oip2(45.0, 12.0), 57.0);
+    let iip2 = intercept::cascade_iip2(&[(40.0, 15.0), (50.0, -7.0)]);
+    assert!((iip2 - 31.13).abs() < 0.01);
+    assert!((intercept::im2_input_dbm(-30.0, iip2) - (-91.13)).abs() < 0.01);
+    assert_eq!(intercept::iip2_from_measurement(-30.0, 70.0), 40.0);
+    assert_eq!(intercept::harmonic_dbc(-10.0, 30.0, 2), -40.0);
+    assert_eq!(intercept::harmonic_intercept_dbm(-10.0, -60.0, 3), 20.0);
+}
+
+// === Section 28: Spur Search ===
+
+#[test]
+fn spur_search() {
+    use rfconversions::spur;
+
+    let products = spur::mixing_products((2.4e9, 2.4e9), 2.0e9, 2);
+    assert_eq!(products.len(), 6);
+
+    let hits = spur::spurs_in_band((1.0e9, 1.1e9), 0.9e9, 5, (100e6, 250e6), -20.0, 15.0);
+    assert!(hits.iter().all(|s| !s.is_desired()));
+    let s = hits
+        .iter()
+        .find(|s| s.rf_order == 2 && s.lo_order == -2)
+        .unwrap();
+    assert!((s.low - 200e6).abs() < 1.0 && (s.high - 400e6).abs() < 1.0);
+    assert_eq!(s.level_dbc, Some(-35.0));
+}
+
+// === Section 29: Superheterodyne Frequency Planning ===
+
+#[test]
+fn superheterodyne_frequency_planning() {
+    use rfconversions::filter::{prototype, Approximation, FilterResponse};
+    use rfconversions::superhet::{self, Injection};
+
+    let lo = superhet::lo_frequency(900e6, 70e6, Injection::LowSide);
+    assert_eq!(lo, 830e6);
+    assert_eq!(superhet::image_frequency(900e6, lo), 760e6);
+    assert_eq!(superhet::half_if_frequency(900e6, lo), 865e6);
+
+    let side = superhet::select_injection((2.4e9, 2.5e9), 400e6, &[(1.6e9, 1.7e9)]);
+    assert_eq!(side, Injection::HighSide);
+
+    let chain = superhet::plan_chain(
+        (10.7e9, 10.8e9),
+        &[(1.2e9, Injection::LowSide), (140e6, Injection::HighSide)],
+    );
+    assert_eq!(chain.len(), 2);
+    assert_eq!(chain[1].output_band, (90e6, 190e6));
+
+    let preselector = prototype(Approximation::Chebyshev { ripple_db: 0.1 }, 5).unwrap();
+    let response = FilterResponse::Bandpass {
+        center: 10.75e9,
+        bandwidth: 150e6,
+    };
+    assert!(chain[0].meets_image_rejection(&preselector, response, 60.0));
+}
+
+// === Section 30: Band Designations ===
+
+#[test]
+fn band_designations() {
+    use rfconversions::band::{self, Scheme};
+
+    let l = band::labels(14.25e9);
+    assert_eq!(l.ieee.unwrap().name, "Ku");
+    assert_eq!(l.itu_number, Some(10));
+    assert_eq!(l.itu.unwrap().name, "SHF");
+    assert_eq!(l.nato.unwrap().name, "J");
+    assert_eq!(l.satellite.unwrap().name, "Ku");
+
+    let ka = band::band_by_name(Scheme::Satellite, "Ka").unwrap();
+    assert_eq!((ka.min_frequency, ka.max_frequency), (26.5e9, 40e9));
+}
+
+// === Section 31: Channel Numbers ===
+
+#[test]
+fn channel_numbers() {
+    use rfconversions::channel::{self, Link, WifiBand};
+
+    assert_eq!(channel::earfcn_to_frequency(1575), Some(1842.5e6));
+    assert_eq!(
+        channel::frequency_to_earfcn(3, Link::Downlink, 1842.5e6),
+        Some(1575)
+    );
+    assert_eq!(channel::nr_arfcn_to_frequency(620000), 3.3e9);
+    assert!((channel::gscn_to_frequency(7711) - 3305.28e6).abs() < 1e-3);
+
+    let center = channel::wifi_center_channel(WifiBand::FiveGhz, 44, 80).unwrap();
+    assert_eq!(center, 42);
+    assert_eq!(
+        channel::wifi_channel_to_frequency(WifiBand::FiveGhz, center),
+        Some(5210e6)
+    );
+    assert_eq!(channel::ble_channel_to_frequency(38), Some(2426e6));
+}
+
+// === Section 32: Doppler ===
+
+#[test]
+fn doppler() {
+    use rfconversions::doppler;
+
+    let shift = doppler::doppler_shift(2.4e9, 30.0);
+    assert!((shift - 240.17).abs() < 0.01);
+    assert!((doppler::radial_velocity_from_shift(2.4e9, shift) - 30.0).abs() < 1e-9);
+    assert!((doppler::radar_doppler_shift(10e9, 30.0) - 2001.38).abs() < 0.01);
+    assert!((doppler::max_leo_doppler_shift(2.2e9, 500e3, 10.0) - 51.04e3).abs() < 0.01e3);
+    assert!((doppler::max_leo_doppler_rate(2.2e9, 500e3) - 789.5).abs() < 0.1);
+}